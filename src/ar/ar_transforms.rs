@@ -0,0 +1,644 @@
+use indexmap::IndexMap;
+use std::sync::LazyLock;
+
+use crate::{
+    ja::ja_transforms::{LanguageTransformerTestCase, TransformTest},
+    transformer::{
+        Condition, ConditionMap, LanguageTransformDescriptor, Rule, RuleType, Transform,
+        TransformMap,
+    },
+    transforms::inflection,
+};
+
+/// Attached pronouns on nouns (possessive) & prepositions.
+const NOUN_PRONOUN_SUFFIXES: [&str; 11] = [
+    "ي", "ك", "ه", "ها", "نا", "كما", "هما", "كم", "كن", "هم", "هن",
+];
+
+/// Attached pronouns on verbs (object), same as [`NOUN_PRONOUN_SUFFIXES`] with `ني` for `ي`.
+const VERB_PRONOUN_SUFFIXES: [&str; 11] = [
+    "ني", "ك", "ه", "ها", "نا", "كما", "هما", "كم", "كن", "هم", "هن",
+];
+
+/// Everything a conjunction (و / ف) can be attached to.
+const AFTER_CONJUNCTION: &[&str] = &[
+    "n",
+    "n_num",
+    "n_def",
+    "n_prep",
+    "n_pron",
+    "v",
+    "v_pf",
+    "v_impf",
+    "v_impf_sfx",
+    "v_imp",
+    "v_imp_sfx",
+    "v_pron",
+    "v_fut",
+];
+
+const AFTER_VERB_PRONOUN: &[&str] = &["v", "v_pf", "v_impf", "v_impf_sfx", "v_imp", "v_imp_sfx"];
+
+pub static ARABIC_TRANSFORMS_DESCRIPTOR: LazyLock<LanguageTransformDescriptor> =
+    LazyLock::new(|| LanguageTransformDescriptor {
+        language: "ar",
+        conditions: &AR_CONDITIONS_MAP,
+        transforms: &AR_TRANSFORMS_MAP,
+    });
+
+pub static AR_CONDITIONS_MAP: LazyLock<ConditionMap> = LazyLock::new(|| {
+    ConditionMap(IndexMap::from([
+        (
+            "n",
+            Condition {
+                name: "Noun",
+                is_dictionary_form: true,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "n_num",
+            Condition {
+                name: "Noun with gender or number suffix",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "n_def",
+            Condition {
+                name: "Noun with definite article",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "n_prep",
+            Condition {
+                name: "Noun with attached preposition",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "n_pron",
+            Condition {
+                name: "Noun with attached pronoun",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v",
+            Condition {
+                name: "Verb",
+                is_dictionary_form: true,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v_pf",
+            Condition {
+                name: "Perfect verb with person suffix",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v_impf",
+            Condition {
+                name: "Imperfect verb",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v_impf_sfx",
+            Condition {
+                name: "Imperfect verb with person suffix",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v_imp",
+            Condition {
+                name: "Imperative verb",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v_imp_sfx",
+            Condition {
+                name: "Imperative verb with person suffix",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v_pron",
+            Condition {
+                name: "Verb with attached pronoun",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v_fut",
+            Condition {
+                name: "Future verb",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "conj",
+            Condition {
+                name: "Word with attached conjunction",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+    ]))
+});
+
+/// Rules removing an attached pronoun from a noun.
+///
+/// Ta marbuta is written as ت before a pronoun (مدرستها -> مدرسة),
+/// and the ن of plurals & duals is dropped (معلموه -> معلمون, كتاباه -> كتابان).
+fn noun_pronoun_rules() -> Vec<Rule> {
+    let mut rules = Vec::new();
+    for suffix in NOUN_PRONOUN_SUFFIXES {
+        rules.push(inflection(
+            suffix,
+            "",
+            &["n_pron"],
+            &["n", "n_num"],
+            RuleType::Suffix,
+        ));
+        rules.push(inflection(
+            &format!("ت{suffix}"),
+            "ة",
+            &["n_pron"],
+            &["n", "n_num"],
+            RuleType::Suffix,
+        ));
+        if suffix != "ي" {
+            rules.push(inflection(
+                &format!("و{suffix}"),
+                "ون",
+                &["n_pron"],
+                &["n_num"],
+                RuleType::Suffix,
+            ));
+            rules.push(inflection(
+                &format!("ا{suffix}"),
+                "ان",
+                &["n_pron"],
+                &["n_num"],
+                RuleType::Suffix,
+            ));
+        }
+    }
+    rules
+}
+
+/// Rules removing an attached object pronoun from a verb.
+///
+/// The silent alef of وا is dropped before a pronoun (كتبوه -> كتبوا).
+fn verb_pronoun_rules() -> Vec<Rule> {
+    let mut rules = Vec::new();
+    for suffix in VERB_PRONOUN_SUFFIXES {
+        rules.push(inflection(
+            suffix,
+            "",
+            &["v_pron"],
+            AFTER_VERB_PRONOUN,
+            RuleType::Suffix,
+        ));
+        rules.push(inflection(
+            &format!("و{suffix}"),
+            "وا",
+            &["v_pron"],
+            &["v_pf", "v_impf_sfx", "v_imp_sfx"],
+            RuleType::Suffix,
+        ));
+    }
+    rules
+}
+
+pub static AR_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
+    TransformMap(IndexMap::from([
+        (
+            "conjunction",
+            Transform {
+                name: "conjunction",
+                description: Some("Conjunction prefix و (and) or ف (so)"),
+                rules: vec![
+                    inflection("و", "", &["conj"], AFTER_CONJUNCTION, RuleType::Prefix),
+                    inflection("ف", "", &["conj"], AFTER_CONJUNCTION, RuleType::Prefix),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "preposition",
+            Transform {
+                name: "preposition",
+                description: Some("Preposition prefix ب (with), ك (like) or ل (for)"),
+                rules: vec![
+                    inflection(
+                        "ب",
+                        "",
+                        &["n_prep"],
+                        &["n", "n_num", "n_def", "n_pron"],
+                        RuleType::Prefix,
+                    ),
+                    inflection(
+                        "ك",
+                        "",
+                        &["n_prep"],
+                        &["n", "n_num", "n_def", "n_pron"],
+                        RuleType::Prefix,
+                    ),
+                    inflection(
+                        "ل",
+                        "",
+                        &["n_prep"],
+                        &["n", "n_num", "n_def", "n_pron"],
+                        RuleType::Prefix,
+                    ),
+                    // ل + ال is written لل
+                    inflection("لل", "ال", &["n_prep"], &["n_def"], RuleType::Prefix),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "definite",
+            Transform {
+                name: "definite",
+                description: Some("Definite article ال"),
+                rules: vec![inflection(
+                    "ال",
+                    "",
+                    &["n_def"],
+                    &["n", "n_num"],
+                    RuleType::Prefix,
+                )],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "feminine",
+            Transform {
+                name: "feminine",
+                description: Some("Feminine form of a noun or adjective (ة)"),
+                rules: vec![inflection("ة", "", &["n_num"], &["n"], RuleType::Suffix)],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "sound masculine plural",
+            Transform {
+                name: "sound masculine plural",
+                description: Some("Sound masculine plural of a noun or adjective (ون / ين)"),
+                rules: vec![
+                    inflection("ون", "", &["n_num"], &["n"], RuleType::Suffix),
+                    inflection("ين", "", &["n_num"], &["n"], RuleType::Suffix),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "sound feminine plural",
+            Transform {
+                name: "sound feminine plural",
+                description: Some("Sound feminine plural of a noun or adjective (ات)"),
+                rules: vec![
+                    inflection("ات", "ة", &["n_num"], &["n", "n_num"], RuleType::Suffix),
+                    inflection("ات", "", &["n_num"], &["n"], RuleType::Suffix),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "dual",
+            Transform {
+                name: "dual",
+                description: Some("Dual form of a noun or adjective (ان / ين)"),
+                rules: vec![
+                    inflection("تان", "ة", &["n_num"], &["n", "n_num"], RuleType::Suffix),
+                    inflection("تين", "ة", &["n_num"], &["n", "n_num"], RuleType::Suffix),
+                    inflection("ان", "", &["n_num"], &["n"], RuleType::Suffix),
+                    inflection("ين", "", &["n_num"], &["n"], RuleType::Suffix),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "possessive pronoun",
+            Transform {
+                name: "possessive pronoun",
+                description: Some("Attached possessive pronoun (كتابي my book, كتابه his book)"),
+                rules: noun_pronoun_rules(),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "perfect",
+            Transform {
+                name: "perfect",
+                description: Some("Person suffix of a perfect (past) verb"),
+                rules: vec![
+                    inflection("ت", "", &["v_pf"], &["v"], RuleType::Suffix),
+                    inflection("تما", "", &["v_pf"], &["v"], RuleType::Suffix),
+                    inflection("تم", "", &["v_pf"], &["v"], RuleType::Suffix),
+                    inflection("تن", "", &["v_pf"], &["v"], RuleType::Suffix),
+                    inflection("نا", "", &["v_pf"], &["v"], RuleType::Suffix),
+                    inflection("وا", "", &["v_pf"], &["v"], RuleType::Suffix),
+                    inflection("تا", "", &["v_pf"], &["v"], RuleType::Suffix),
+                    inflection("ا", "", &["v_pf"], &["v"], RuleType::Suffix),
+                    inflection("ن", "", &["v_pf"], &["v"], RuleType::Suffix),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "imperfect",
+            Transform {
+                name: "imperfect",
+                description: Some("Person prefix of an imperfect (present) verb"),
+                rules: vec![
+                    inflection("أ", "", &["v_impf"], &["v"], RuleType::Prefix),
+                    inflection("ت", "", &["v_impf"], &["v"], RuleType::Prefix),
+                    inflection("ي", "", &["v_impf"], &["v"], RuleType::Prefix),
+                    inflection("ن", "", &["v_impf"], &["v"], RuleType::Prefix),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "imperfect suffix",
+            Transform {
+                name: "imperfect suffix",
+                description: Some("Person suffix of an imperfect (present) verb"),
+                rules: vec![
+                    inflection("ون", "", &["v_impf_sfx"], &["v_impf"], RuleType::Suffix),
+                    inflection("ين", "", &["v_impf_sfx"], &["v_impf"], RuleType::Suffix),
+                    inflection("ان", "", &["v_impf_sfx"], &["v_impf"], RuleType::Suffix),
+                    inflection("وا", "", &["v_impf_sfx"], &["v_impf"], RuleType::Suffix),
+                    inflection("ن", "", &["v_impf_sfx"], &["v_impf"], RuleType::Suffix),
+                    inflection("ي", "", &["v_impf_sfx"], &["v_impf"], RuleType::Suffix),
+                    inflection("ا", "", &["v_impf_sfx"], &["v_impf"], RuleType::Suffix),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "future",
+            Transform {
+                name: "future",
+                description: Some("Future prefix س"),
+                rules: vec![inflection(
+                    "س",
+                    "",
+                    &["v_fut"],
+                    &["v_impf", "v_impf_sfx", "v_pron"],
+                    RuleType::Prefix,
+                )],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "imperative",
+            Transform {
+                name: "imperative",
+                description: Some("Imperative form of a verb"),
+                rules: vec![inflection("ا", "", &["v_imp"], &["v"], RuleType::Prefix)],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "imperative suffix",
+            Transform {
+                name: "imperative suffix",
+                description: Some("Person suffix of an imperative verb"),
+                rules: vec![
+                    inflection("وا", "", &["v_imp_sfx"], &["v_imp"], RuleType::Suffix),
+                    inflection("ي", "", &["v_imp_sfx"], &["v_imp"], RuleType::Suffix),
+                    inflection("ا", "", &["v_imp_sfx"], &["v_imp"], RuleType::Suffix),
+                    inflection("ن", "", &["v_imp_sfx"], &["v_imp"], RuleType::Suffix),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "object pronoun",
+            Transform {
+                name: "object pronoun",
+                description: Some("Attached object pronoun (كتبه he wrote it)"),
+                rules: verb_pronoun_rules(),
+                i18n: None,
+                weight: None,
+            },
+        ),
+    ]))
+});
+
+pub(crate) static AR_TRANSFORM_TESTS: LazyLock<[&[TransformTest]; 2]> =
+    LazyLock::new(|| [&*AR_NOUN_TESTS, &*AR_VERB_TESTS]);
+
+pub(crate) static AR_NOUN_TESTS: LazyLock<[TransformTest; 3]> = LazyLock::new(|| {
+    [
+        TransformTest {
+            term: "كتاب",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "الكتاب",
+                    rule: "n",
+                    reasons: vec!["definite"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "بالكتاب",
+                    rule: "n",
+                    reasons: vec!["definite", "preposition"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "للكتاب",
+                    rule: "n",
+                    reasons: vec!["definite", "preposition"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "والكتاب",
+                    rule: "n",
+                    reasons: vec!["definite", "conjunction"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "كتابها",
+                    rule: "n",
+                    reasons: vec!["possessive pronoun"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "بكتابهم",
+                    rule: "n",
+                    reasons: vec!["possessive pronoun", "preposition"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "مدرسة",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "مدرستها",
+                    rule: "n",
+                    reasons: vec!["possessive pronoun"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "مدرستان",
+                    rule: "n",
+                    reasons: vec!["dual"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "معلم",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "معلمة",
+                    rule: "n",
+                    reasons: vec!["feminine"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "معلمون",
+                    rule: "n",
+                    reasons: vec!["sound masculine plural"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "المعلمون",
+                    rule: "n",
+                    reasons: vec!["sound masculine plural", "definite"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "معلموك",
+                    rule: "n",
+                    reasons: vec!["sound masculine plural", "possessive pronoun"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "معلمات",
+                    rule: "n",
+                    reasons: vec!["sound feminine plural"],
+                },
+            ],
+        },
+    ]
+});
+
+pub(crate) static AR_VERB_TESTS: LazyLock<[TransformTest; 1]> = LazyLock::new(|| {
+    [TransformTest {
+        term: "كتب",
+        sources: vec![
+            LanguageTransformerTestCase {
+                inner: "كتبت",
+                rule: "v",
+                reasons: vec!["perfect"],
+            },
+            LanguageTransformerTestCase {
+                inner: "كتبوا",
+                rule: "v",
+                reasons: vec!["perfect"],
+            },
+            LanguageTransformerTestCase {
+                inner: "كتبناه",
+                rule: "v",
+                reasons: vec!["perfect", "object pronoun"],
+            },
+            LanguageTransformerTestCase {
+                inner: "يكتب",
+                rule: "v",
+                reasons: vec!["imperfect"],
+            },
+            LanguageTransformerTestCase {
+                inner: "يكتبون",
+                rule: "v",
+                reasons: vec!["imperfect", "imperfect suffix"],
+            },
+            LanguageTransformerTestCase {
+                inner: "سيكتبها",
+                rule: "v",
+                reasons: vec!["imperfect", "object pronoun", "future"],
+            },
+            LanguageTransformerTestCase {
+                inner: "فكتبوه",
+                rule: "v",
+                reasons: vec!["perfect", "object pronoun", "conjunction"],
+            },
+            LanguageTransformerTestCase {
+                inner: "اكتبوا",
+                rule: "v",
+                reasons: vec!["imperative", "imperative suffix"],
+            },
+        ],
+    }]
+});
+
+#[cfg(test)]
+mod artransforms {
+    use crate::{
+        ar::ar_transforms::{ARABIC_TRANSFORMS_DESCRIPTOR, AR_TRANSFORM_TESTS},
+        ja::ja_transforms::has_term_reasons,
+        transformer::LanguageTransformer,
+    };
+
+    #[test]
+    fn transforms() {
+        let mut lt = LanguageTransformer::new();
+        lt.add_descriptor(&ARABIC_TRANSFORMS_DESCRIPTOR).unwrap();
+
+        for test_vec in AR_TRANSFORM_TESTS.into_iter() {
+            for test in test_vec {
+                let term = test.term;
+                for case in &test.sources {
+                    let source = case.inner;
+                    let rule = case.rule;
+                    let expected_reasons = &case.reasons;
+
+                    let result =
+                        has_term_reasons(&lt, source, term, Some(rule), Some(expected_reasons));
+                    if let Err(e) = result {
+                        panic!("Failed: {e}");
+                    }
+                }
+            }
+        }
+    }
+}
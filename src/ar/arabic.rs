@@ -0,0 +1,113 @@
+use crate::arabic_utils::is_string_partially_arabic_script;
+
+pub const ALEF: char = 'ا';
+pub const ALEF_WITH_HAMZA_ABOVE: char = 'أ';
+pub const ALEF_WITH_HAMZA_BELOW: char = 'إ';
+pub const ALEF_WITH_MADDA_ABOVE: char = 'آ';
+pub const ALEF_WASLA: char = 'ٱ';
+pub const ALEF_MAKSURA: char = 'ى';
+pub const YEH: char = 'ي';
+
+/// Checks if a string contains at least one Arabic character.
+pub fn is_string_partially_arabic(s: &str) -> bool {
+    is_string_partially_arabic_script(s)
+}
+
+/// Writes every alef carrying a hamza, madda or wasla as a bare alef.
+///
+/// `أكل` -> `اكل`, `إسلام` -> `اسلام`, `آخر` -> `اخر`
+pub fn normalize_alef_variants(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            ALEF_WITH_HAMZA_ABOVE | ALEF_WITH_HAMZA_BELOW | ALEF_WITH_MADDA_ABOVE | ALEF_WASLA => {
+                ALEF
+            }
+            c => c,
+        })
+        .collect()
+}
+
+/// Replaces a word-initial bare alef with `alef`.
+///
+/// Hamzas are often left out in casual writing, and a word-initial alef
+/// is almost always a hamzated one.
+fn replace_initial_alef(s: &str, alef: char) -> String {
+    let mut result = String::with_capacity(s.len());
+    let mut at_word_start = true;
+    for c in s.chars() {
+        if at_word_start && c == ALEF {
+            result.push(alef);
+        } else {
+            result.push(c);
+        }
+        at_word_start = c.is_whitespace();
+    }
+    result
+}
+
+/// `اكل` -> `أكل`
+pub fn add_hamza_top(s: &str) -> String {
+    replace_initial_alef(s, ALEF_WITH_HAMZA_ABOVE)
+}
+
+/// `اسلام` -> `إسلام`
+pub fn add_hamza_bottom(s: &str) -> String {
+    replace_initial_alef(s, ALEF_WITH_HAMZA_BELOW)
+}
+
+/// `على` -> `علي`
+pub fn convert_alef_maksura_to_yeh(s: &str) -> String {
+    s.replace(ALEF_MAKSURA, &YEH.to_string())
+}
+
+#[cfg(test)]
+mod ar_tests {
+    use super::*;
+    use crate::arabic_utils::{remove_arabic_script_diacritics, remove_tatweel};
+
+    #[test]
+    fn partially_arabic() {
+        assert!(is_string_partially_arabic("كتاب"));
+        assert!(is_string_partially_arabic("the word كتاب"));
+        assert!(is_string_partially_arabic("ﻛﺘﺎﺏ"));
+        assert!(!is_string_partially_arabic("kitab"));
+        assert!(!is_string_partially_arabic("読む"));
+    }
+
+    #[test]
+    fn diacritics_and_tatweel() {
+        assert_eq!(remove_arabic_script_diacritics("كَتَبَ"), "كتب");
+        assert_eq!(remove_arabic_script_diacritics("مُدَرِّسَةٌ"), "مدرسة");
+        assert_eq!(remove_arabic_script_diacritics("هٰذا"), "هذا");
+        assert_eq!(remove_tatweel("كـــتاب"), "كتاب");
+    }
+
+    #[test]
+    fn alef_variants() {
+        assert_eq!(normalize_alef_variants("أكل"), "اكل");
+        assert_eq!(normalize_alef_variants("إسلام"), "اسلام");
+        assert_eq!(normalize_alef_variants("آخر"), "اخر");
+        assert_eq!(normalize_alef_variants("ٱلكتاب"), "الكتاب");
+        assert_eq!(add_hamza_top("اكل"), "أكل");
+        assert_eq!(add_hamza_top("اكل احمد"), "أكل أحمد");
+        assert_eq!(add_hamza_bottom("اسلام"), "إسلام");
+        assert_eq!(add_hamza_top("كتاب"), "كتاب");
+        assert_eq!(convert_alef_maksura_to_yeh("على"), "علي");
+    }
+
+    #[test]
+    fn language_descriptor() {
+        use crate::languages::{get_all_language_text_processors, is_text_lookup_worthy};
+
+        assert!(is_text_lookup_worthy("يكتبون", "ar"));
+        assert!(!is_text_lookup_worthy("yaktubun", "ar"));
+        let processors = get_all_language_text_processors()
+            .into_iter()
+            .find(|p| p.iso == "ar")
+            .unwrap();
+        let text = processors.pre.iter().fold("وَأَكـتُبُ".to_string(), |text, p| {
+            (p.processor.process)(&text, p.processor.options[1].clone())
+        });
+        assert_eq!(text, "واكتب");
+    }
+}
@@ -0,0 +1,2 @@
+pub mod ar_transforms;
+pub mod arabic;
@@ -0,0 +1,66 @@
+#![allow(dead_code)]
+
+use crate::cjk_utils::{is_code_point_in_ranges, CodepointRange};
+
+// --- Arabic Script Ranges ---
+pub const ARABIC_RANGE: CodepointRange = (0x0600, 0x06ff);
+pub const ARABIC_SUPPLEMENT_RANGE: CodepointRange = (0x0750, 0x077f);
+pub const ARABIC_EXTENDED_B_RANGE: CodepointRange = (0x0870, 0x089f);
+pub const ARABIC_EXTENDED_A_RANGE: CodepointRange = (0x08a0, 0x08ff);
+pub const ARABIC_PRESENTATION_FORMS_A_RANGE: CodepointRange = (0xfb50, 0xfdff);
+pub const ARABIC_PRESENTATION_FORMS_B_RANGE: CodepointRange = (0xfe70, 0xfeff);
+
+/// Ranges used by every language written in the Arabic script (Arabic, Persian, Urdu...),
+/// roughly ordered in order of expected frequency.
+pub const ARABIC_SCRIPT_RANGES: [CodepointRange; 6] = [
+    ARABIC_RANGE,
+    ARABIC_PRESENTATION_FORMS_B_RANGE,
+    ARABIC_PRESENTATION_FORMS_A_RANGE,
+    ARABIC_SUPPLEMENT_RANGE,
+    ARABIC_EXTENDED_A_RANGE,
+    ARABIC_EXTENDED_B_RANGE,
+];
+
+// --- Marks ---
+/// Fathatan (U+064B) to Wavy Hamza Below (U+065F): the harakat, shadda, sukun and hamza marks.
+pub const ARABIC_HARAKAT_RANGE: CodepointRange = (0x064b, 0x065f);
+/// Small high ligatures & Quranic annotation signs.
+pub const ARABIC_QURANIC_MARKS_RANGE: CodepointRange = (0x06d6, 0x06ed);
+pub const ARABIC_SUPERSCRIPT_ALEF: char = '\u{0670}';
+pub const ARABIC_TATWEEL: char = '\u{0640}';
+
+/// All tashkeel (diacritic) ranges, see [`remove_arabic_script_diacritics`].
+pub const ARABIC_DIACRITIC_RANGES: [CodepointRange; 3] = [
+    ARABIC_HARAKAT_RANGE,
+    (
+        ARABIC_SUPERSCRIPT_ALEF as u32,
+        ARABIC_SUPERSCRIPT_ALEF as u32,
+    ),
+    ARABIC_QURANIC_MARKS_RANGE,
+];
+
+/// Checks if a string contains at least one character written in the Arabic script.
+pub fn is_string_partially_arabic_script(s: &str) -> bool {
+    s.chars().any(|c| is_code_point_arabic_script(c as u32))
+}
+
+/// Checks if a given Unicode code point is within [`ARABIC_SCRIPT_RANGES`].
+pub fn is_code_point_arabic_script(code_point: u32) -> bool {
+    is_code_point_in_ranges(code_point, &ARABIC_SCRIPT_RANGES)
+}
+
+/// Removes tashkeel (harakat, shadda, sukun, superscript alef & Quranic marks).
+///
+/// `كَتَبَ` -> `كتب`
+pub fn remove_arabic_script_diacritics(s: &str) -> String {
+    s.chars()
+        .filter(|&c| !is_code_point_in_ranges(c as u32, &ARABIC_DIACRITIC_RANGES))
+        .collect()
+}
+
+/// Removes tatweel (kashida), the elongation character used for justification.
+///
+/// `كـــتاب` -> `كتاب`
+pub fn remove_tatweel(s: &str) -> String {
+    s.chars().filter(|&c| c != ARABIC_TATWEEL).collect()
+}
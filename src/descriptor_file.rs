@@ -0,0 +1,879 @@
+//! Runtime-loadable [`LanguageTransformDescriptor`]s.
+//!
+//! Descriptors compiled into the crate are built from `&'static` data,
+//! so a loaded descriptor file leaks its strings to get the same lifetimes.
+//! Load a language once and reuse the returned descriptor.
+
+use std::path::Path;
+
+use fancy_regex::Regex;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use snafu::{ResultExt, Snafu};
+
+use crate::{
+    transformer::{
+        deserialize_regex, Condition, ConditionMap, DeinflectFnType, LanguageTransformDescriptor,
+        LanguageTransformer, LanguageTransformerError, Rule, RuleI18n, RuleType, Transform,
+        TransformI18n, TransformMap,
+    },
+    transforms::{compiled_inflection, inflection_pattern},
+};
+
+/// Errors for [`LanguageTransformDescriptorFile`].
+#[derive(Snafu, Debug)]
+pub enum DescriptorFileError {
+    #[snafu(display("failed to read descriptor file: {path}"))]
+    Io {
+        source: std::io::Error,
+        path: String,
+    },
+    #[snafu(display("unsupported descriptor file extension (expected .json or .toml): {path}"))]
+    UnknownFormat { path: String },
+    #[snafu(display("invalid json descriptor"))]
+    Json { source: serde_json::Error },
+    #[snafu(display("invalid toml descriptor"))]
+    TomlDe { source: toml::de::Error },
+    #[snafu(display("failed to write toml descriptor"))]
+    TomlSer { source: toml::ser::Error },
+    #[snafu(display("{transform_id}.rules[{index}] is missing `{field}`"))]
+    MissingField {
+        transform_id: String,
+        index: usize,
+        field: &'static str,
+    },
+    #[snafu(display(
+        "{transform_id}.rules[{index}] uses a custom deinflect function and can't be imported"
+    ))]
+    UnsupportedRule { transform_id: String, index: usize },
+    #[snafu(display(
+        "{transform_id}.rules[{index}] pattern `{pattern}` doesn't match its rule type"
+    ))]
+    InvalidPattern {
+        transform_id: String,
+        index: usize,
+        pattern: String,
+    },
+    #[snafu(display("failed to add descriptor"))]
+    AddDescriptor { source: LanguageTransformerError },
+    #[snafu(display("{transform_id}.rules[{index}] doesn't compile to a valid regex"))]
+    InvalidRegex {
+        source: Box<fancy_regex::Error>,
+        transform_id: String,
+        index: usize,
+    },
+}
+
+/// Serializable form of a [`LanguageTransformDescriptor`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LanguageTransformDescriptorFile {
+    pub language: String,
+    pub conditions: IndexMap<String, ConditionFile>,
+    pub transforms: IndexMap<String, TransformFile>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConditionFile {
+    pub name: String,
+    #[serde(default)]
+    pub is_dictionary_form: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub_conditions: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub i18n: Option<Vec<RuleI18nFile>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuleI18nFile {
+    pub language: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransformFile {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub i18n: Option<Vec<TransformI18nFile>>,
+    pub rules: Vec<RuleFile>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransformI18nFile {
+    pub language: String,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/// `suffix`, `prefix` & `wholeWord` rules only need `inflected` and `deinflected`.
+/// `other` rules also need the `is_inflected` regex and a `deinflect_fn`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RuleFile {
+    #[serde(rename = "type")]
+    pub rule_type: RuleType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inflected: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deinflected: Option<String>,
+    #[serde(default)]
+    pub conditions_in: Vec<String>,
+    #[serde(default)]
+    pub conditions_out: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_inflected: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deinflect_fn: Option<DeinflectFnFile>,
+}
+
+/// Owned mirror of [`DeinflectFnType`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeinflectFnFile {
+    GenericSuffix,
+    GenericPrefix,
+    GenericWholeWord,
+    EnCreatePhrasalVerbInflection,
+    EnPhrasalVerbInterposedObjectRule,
+    GenericStemChange {
+        stem_from: String,
+        stem_to: String,
+        ending_re: String,
+        ending_to: String,
+    },
+    SpecialCasedStemChange {
+        prefix: String,
+        special_stem_from: String,
+        special_stem_to: String,
+        default_stem_from: String,
+        default_stem_to: String,
+        ending_re: String,
+        ending_to: String,
+    },
+    Pronominal,
+}
+
+impl From<DeinflectFnType> for DeinflectFnFile {
+    fn from(value: DeinflectFnType) -> Self {
+        match value {
+            DeinflectFnType::GenericSuffix => Self::GenericSuffix,
+            DeinflectFnType::GenericPrefix => Self::GenericPrefix,
+            DeinflectFnType::GenericWholeWord => Self::GenericWholeWord,
+            DeinflectFnType::EnCreatePhrasalVerbInflection => Self::EnCreatePhrasalVerbInflection,
+            DeinflectFnType::EnPhrasalVerbInterposedObjectRule => {
+                Self::EnPhrasalVerbInterposedObjectRule
+            }
+            DeinflectFnType::GenericStemChange {
+                stem_from,
+                stem_to,
+                ending_re,
+                ending_to,
+            } => Self::GenericStemChange {
+                stem_from: stem_from.into(),
+                stem_to: stem_to.into(),
+                ending_re: ending_re.into(),
+                ending_to: ending_to.into(),
+            },
+            DeinflectFnType::SpecialCasedStemChange {
+                prefix,
+                special_stem_from,
+                special_stem_to,
+                default_stem_from,
+                default_stem_to,
+                ending_re,
+                ending_to,
+            } => Self::SpecialCasedStemChange {
+                prefix: prefix.into(),
+                special_stem_from: special_stem_from.into(),
+                special_stem_to: special_stem_to.into(),
+                default_stem_from: default_stem_from.into(),
+                default_stem_to: default_stem_to.into(),
+                ending_re: ending_re.into(),
+                ending_to: ending_to.into(),
+            },
+            DeinflectFnType::Pronominal => Self::Pronominal,
+        }
+    }
+}
+
+impl From<DeinflectFnFile> for DeinflectFnType {
+    fn from(value: DeinflectFnFile) -> Self {
+        match value {
+            DeinflectFnFile::GenericSuffix => Self::GenericSuffix,
+            DeinflectFnFile::GenericPrefix => Self::GenericPrefix,
+            DeinflectFnFile::GenericWholeWord => Self::GenericWholeWord,
+            DeinflectFnFile::EnCreatePhrasalVerbInflection => Self::EnCreatePhrasalVerbInflection,
+            DeinflectFnFile::EnPhrasalVerbInterposedObjectRule => {
+                Self::EnPhrasalVerbInterposedObjectRule
+            }
+            DeinflectFnFile::GenericStemChange {
+                stem_from,
+                stem_to,
+                ending_re,
+                ending_to,
+            } => Self::GenericStemChange {
+                stem_from: stem_from.leak(),
+                stem_to: stem_to.leak(),
+                ending_re: ending_re.leak(),
+                ending_to: ending_to.leak(),
+            },
+            DeinflectFnFile::SpecialCasedStemChange {
+                prefix,
+                special_stem_from,
+                special_stem_to,
+                default_stem_from,
+                default_stem_to,
+                ending_re,
+                ending_to,
+            } => Self::SpecialCasedStemChange {
+                prefix: prefix.leak(),
+                special_stem_from: special_stem_from.leak(),
+                special_stem_to: special_stem_to.leak(),
+                default_stem_from: default_stem_from.leak(),
+                default_stem_to: default_stem_to.leak(),
+                ending_re: ending_re.leak(),
+                ending_to: ending_to.leak(),
+            },
+            DeinflectFnFile::Pronominal => Self::Pronominal,
+        }
+    }
+}
+
+fn leak_str_slice(strs: Vec<String>) -> &'static [&'static str] {
+    strs.into_iter()
+        .map(|s| &*s.leak())
+        .collect::<Vec<&'static str>>()
+        .leak()
+}
+
+impl LanguageTransformDescriptorFile {
+    pub fn from_json(json: &str) -> Result<Self, DescriptorFileError> {
+        serde_json::from_str(json).context(JsonSnafu)
+    }
+
+    pub fn from_toml(toml: &str) -> Result<Self, DescriptorFileError> {
+        toml::from_str(toml).context(TomlDeSnafu)
+    }
+
+    /// Reads a `.json` or `.toml` descriptor file.
+    pub fn from_path(path: impl AsRef<Path>) -> Result<Self, DescriptorFileError> {
+        let path = path.as_ref();
+        let display = path.display().to_string();
+        let contents = std::fs::read_to_string(path).context(IoSnafu {
+            path: display.clone(),
+        })?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Self::from_json(&contents),
+            Some("toml") => Self::from_toml(&contents),
+            _ => Err(DescriptorFileError::UnknownFormat { path: display }),
+        }
+    }
+
+    pub fn to_json(&self) -> Result<String, DescriptorFileError> {
+        serde_json::to_string_pretty(self).context(JsonSnafu)
+    }
+
+    pub fn to_toml(&self) -> Result<String, DescriptorFileError> {
+        toml::to_string_pretty(self).context(TomlSerSnafu)
+    }
+
+    /// Builds a descriptor that can be passed to
+    /// [`LanguageTransformer::add_descriptor`](crate::transformer::LanguageTransformer::add_descriptor).
+    ///
+    /// The file's strings are leaked to satisfy the descriptor's `&'static` fields.
+    pub fn into_descriptor(self) -> Result<LanguageTransformDescriptor, DescriptorFileError> {
+        // every rule is checked before anything is leaked
+        let patterns = self
+            .transforms
+            .iter()
+            .map(|(transform_id, transform)| {
+                transform
+                    .rules
+                    .iter()
+                    .enumerate()
+                    .map(|(index, rule)| rule.compile(transform_id, index))
+                    .collect::<Result<Vec<Regex>, DescriptorFileError>>()
+            })
+            .collect::<Result<Vec<_>, DescriptorFileError>>()?;
+
+        let conditions: IndexMap<&'static str, Condition> = self
+            .conditions
+            .into_iter()
+            .map(|(condition_type, condition)| {
+                let condition = Condition {
+                    name: condition.name.leak(),
+                    is_dictionary_form: condition.is_dictionary_form,
+                    i18n: condition.i18n.map(|i18n| {
+                        i18n.into_iter()
+                            .map(|i18n| RuleI18n {
+                                language: i18n.language.leak(),
+                                name: i18n.name.leak(),
+                            })
+                            .collect()
+                    }),
+                    sub_conditions: condition.sub_conditions.map(leak_str_slice),
+                };
+                (&*condition_type.leak(), condition)
+            })
+            .collect();
+
+        let mut transforms: IndexMap<&'static str, Transform> =
+            IndexMap::with_capacity(self.transforms.len());
+        for ((transform_id, transform), patterns) in self.transforms.into_iter().zip(patterns) {
+            let rules = transform
+                .rules
+                .into_iter()
+                .zip(patterns)
+                .map(|(rule, is_inflected)| rule.into_rule(is_inflected))
+                .collect();
+            let transform = Transform {
+                name: transform.name.leak(),
+                description: transform.description.map(|d| &*d.leak()),
+                i18n: transform.i18n.map(|i18n| {
+                    i18n.into_iter()
+                        .map(|i18n| TransformI18n {
+                            language: i18n.language.leak(),
+                            name: i18n.name.leak(),
+                            description: i18n.description.map(|d| &*d.leak()),
+                        })
+                        .collect()
+                }),
+                rules,
+                weight: transform.weight,
+            };
+            transforms.insert(transform_id.leak(), transform);
+        }
+
+        Ok(LanguageTransformDescriptor {
+            language: self.language.leak(),
+            conditions: Box::leak(Box::new(ConditionMap(conditions))),
+            transforms: Box::leak(Box::new(TransformMap(transforms))),
+        })
+    }
+}
+
+impl RuleFile {
+    /// Checks the rule's fields & compiles its pattern, without leaking anything.
+    fn compile(&self, transform_id: &str, index: usize) -> Result<Regex, DescriptorFileError> {
+        let missing = |field| DescriptorFileError::MissingField {
+            transform_id: transform_id.to_string(),
+            index,
+            field,
+        };
+        let pattern = if self.rule_type == RuleType::Other {
+            if self.deinflect_fn.is_none() {
+                return Err(missing("deinflect_fn"));
+            }
+            self.is_inflected
+                .clone()
+                .ok_or_else(|| missing("is_inflected"))?
+        } else {
+            let inflected = self
+                .inflected
+                .as_deref()
+                .ok_or_else(|| missing("inflected"))?;
+            inflection_pattern(inflected, self.rule_type)
+        };
+        Regex::new(&pattern)
+            .map_err(Box::new)
+            .context(InvalidRegexSnafu {
+                transform_id: transform_id.to_string(),
+                index,
+            })
+    }
+
+    /// Builds the rule from the pattern returned by [`RuleFile::compile`].
+    fn into_rule(self, is_inflected: Regex) -> Rule {
+        let conditions_in = leak_str_slice(self.conditions_in);
+        let conditions_out = leak_str_slice(self.conditions_out);
+        if self.rule_type != RuleType::Other {
+            return compiled_inflection(
+                is_inflected,
+                &self.inflected.unwrap_or_default(),
+                self.deinflected.unwrap_or_default().leak(),
+                conditions_in,
+                conditions_out,
+                self.rule_type,
+            );
+        }
+        Rule {
+            rule_type: RuleType::Other,
+            is_inflected,
+            inflected_str: self.inflected,
+            deinflected: self.deinflected.map(|d| &*d.leak()),
+            deinflect_fn: self
+                .deinflect_fn
+                .expect("checked by RuleFile::compile")
+                .into(),
+            conditions_in,
+            conditions_out,
+        }
+    }
+}
+
+impl From<&Rule> for RuleFile {
+    fn from(rule: &Rule) -> Self {
+        let to_strings = |strs: &[&str]| strs.iter().map(|s| s.to_string()).collect();
+        let is_other = rule.rule_type == RuleType::Other;
+        Self {
+            rule_type: rule.rule_type,
+            inflected: rule.inflected_str.clone(),
+            deinflected: match (is_other, rule.deinflected) {
+                (false, None) => Some(String::new()),
+                (_, deinflected) => deinflected.map(str::to_string),
+            },
+            conditions_in: to_strings(rule.conditions_in),
+            conditions_out: to_strings(rule.conditions_out),
+            is_inflected: is_other.then(|| rule.is_inflected.as_str().to_string()),
+            deinflect_fn: is_other.then(|| rule.deinflect_fn.into()),
+        }
+    }
+}
+
+impl From<&LanguageTransformDescriptor> for LanguageTransformDescriptorFile {
+    fn from(descriptor: &LanguageTransformDescriptor) -> Self {
+        let conditions = descriptor
+            .conditions
+            .iter()
+            .map(|(condition_type, condition)| {
+                let condition = ConditionFile {
+                    name: condition.name.to_string(),
+                    is_dictionary_form: condition.is_dictionary_form,
+                    sub_conditions: condition
+                        .sub_conditions
+                        .map(|subs| subs.iter().map(|s| s.to_string()).collect()),
+                    i18n: condition.i18n.as_ref().map(|i18n| {
+                        i18n.iter()
+                            .map(|i18n| RuleI18nFile {
+                                language: i18n.language.to_string(),
+                                name: i18n.name.to_string(),
+                            })
+                            .collect()
+                    }),
+                };
+                (condition_type.to_string(), condition)
+            })
+            .collect();
+
+        let transforms = descriptor
+            .transforms
+            .iter()
+            .map(|(transform_id, transform)| {
+                let transform = TransformFile {
+                    name: transform.name.to_string(),
+                    description: transform.description.map(str::to_string),
+                    i18n: transform.i18n.as_ref().map(|i18n| {
+                        i18n.iter()
+                            .map(|i18n| TransformI18nFile {
+                                language: i18n.language.to_string(),
+                                name: i18n.name.to_string(),
+                                description: i18n.description.map(str::to_string),
+                            })
+                            .collect()
+                    }),
+                    rules: transform.rules.iter().map(RuleFile::from).collect(),
+                    weight: transform.weight,
+                };
+                (transform_id.to_string(), transform)
+            })
+            .collect();
+
+        Self {
+            language: descriptor.language.to_string(),
+            conditions,
+            transforms,
+        }
+    }
+}
+
+/// A `LanguageTransformDescriptor` as serialized by `JSON.stringify` in yomitan.
+///
+/// `deinflect` functions are dropped by `JSON.stringify`, so every rule is rebuilt from its
+/// `type`, `isInflected` pattern & `deinflected` string, see [`deserialize_regex`] for the
+/// accepted `isInflected` formats. Each rule must look like:
+///
+/// ```json
+/// { "type": "suffix", "isInflected": "/ied$/", "deinflected": "y",
+///   "conditionsIn": ["v"], "conditionsOut": ["v"] }
+/// ```
+///
+/// yomitan's `suffixInflection` serializes `deinflected`, but `prefixInflection` &
+/// `wholeWordInflection` only keep it in their `deinflect` closure: those rules must have
+/// `deinflected` added when exporting and are rejected without it.
+/// Patterns with regex syntax in them are kept as regexes instead of literal affixes.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct YomitanDescriptor {
+    language: String,
+    conditions: IndexMap<String, YomitanCondition>,
+    transforms: IndexMap<String, YomitanTransform>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct YomitanCondition {
+    name: String,
+    #[serde(default)]
+    is_dictionary_form: bool,
+    #[serde(default)]
+    sub_conditions: Option<Vec<String>>,
+    #[serde(default)]
+    i18n: Option<Vec<RuleI18nFile>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct YomitanTransform {
+    name: String,
+    #[serde(default)]
+    description: Option<String>,
+    #[serde(default)]
+    i18n: Option<Vec<TransformI18nFile>>,
+    rules: Vec<YomitanRule>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct YomitanRule {
+    #[serde(rename = "type")]
+    rule_type: RuleType,
+    #[serde(deserialize_with = "deserialize_regex")]
+    is_inflected: String,
+    #[serde(default)]
+    deinflected: Option<String>,
+    #[serde(default)]
+    conditions_in: Vec<String>,
+    #[serde(default)]
+    conditions_out: Vec<String>,
+}
+
+impl YomitanRule {
+    fn into_rule_file(
+        self,
+        transform_id: &str,
+        index: usize,
+    ) -> Result<RuleFile, DescriptorFileError> {
+        let pattern = self.is_inflected.as_str();
+        let inflected = match self.rule_type {
+            RuleType::Suffix => pattern.strip_suffix('$'),
+            RuleType::Prefix => pattern.strip_prefix('^'),
+            RuleType::WholeWord => pattern.strip_prefix('^').and_then(|p| p.strip_suffix('$')),
+            RuleType::Other => {
+                return Err(DescriptorFileError::UnsupportedRule {
+                    transform_id: transform_id.to_string(),
+                    index,
+                })
+            }
+        };
+        let Some(inflected) = inflected else {
+            return Err(DescriptorFileError::InvalidPattern {
+                transform_id: transform_id.to_string(),
+                index,
+                pattern: self.is_inflected,
+            });
+        };
+        let Some(deinflected) = self.deinflected else {
+            return Err(DescriptorFileError::MissingField {
+                transform_id: transform_id.to_string(),
+                index,
+                field: "deinflected",
+            });
+        };
+        if fancy_regex::escape(inflected) == inflected {
+            return Ok(RuleFile {
+                rule_type: self.rule_type,
+                inflected: Some(inflected.to_string()),
+                deinflected: Some(deinflected),
+                conditions_in: self.conditions_in,
+                conditions_out: self.conditions_out,
+                is_inflected: None,
+                deinflect_fn: None,
+            });
+        }
+        // not a literal affix, keep the whole pattern as a regex
+        let deinflect_fn = match self.rule_type {
+            RuleType::Suffix => DeinflectFnFile::GenericSuffix,
+            RuleType::WholeWord => DeinflectFnFile::GenericWholeWord,
+            // prefixes are deinflected by stripping their literal text
+            _ => {
+                return Err(DescriptorFileError::InvalidPattern {
+                    transform_id: transform_id.to_string(),
+                    index,
+                    pattern: self.is_inflected,
+                })
+            }
+        };
+        Ok(RuleFile {
+            rule_type: RuleType::Other,
+            inflected: None,
+            deinflected: Some(deinflected),
+            conditions_in: self.conditions_in,
+            conditions_out: self.conditions_out,
+            is_inflected: Some(self.is_inflected),
+            deinflect_fn: Some(deinflect_fn),
+        })
+    }
+}
+
+impl LanguageTransformDescriptorFile {
+    /// Imports the `JSON.stringify` output of a yomitan `LanguageTransformDescriptor`.
+    pub fn from_yomitan_json(json: &str) -> Result<Self, DescriptorFileError> {
+        let descriptor: YomitanDescriptor = serde_json::from_str(json).context(JsonSnafu)?;
+        let conditions = descriptor
+            .conditions
+            .into_iter()
+            .map(|(condition_type, condition)| {
+                let condition = ConditionFile {
+                    name: condition.name,
+                    is_dictionary_form: condition.is_dictionary_form,
+                    sub_conditions: condition.sub_conditions,
+                    i18n: condition.i18n,
+                };
+                (condition_type, condition)
+            })
+            .collect();
+        let mut transforms = IndexMap::with_capacity(descriptor.transforms.len());
+        for (transform_id, transform) in descriptor.transforms {
+            let rules = transform
+                .rules
+                .into_iter()
+                .enumerate()
+                .map(|(index, rule)| rule.into_rule_file(&transform_id, index))
+                .collect::<Result<Vec<RuleFile>, DescriptorFileError>>()?;
+            let transform = TransformFile {
+                name: transform.name,
+                description: transform.description,
+                i18n: transform.i18n,
+                rules,
+                weight: None,
+            };
+            transforms.insert(transform_id, transform);
+        }
+        Ok(Self {
+            language: descriptor.language,
+            conditions,
+            transforms,
+        })
+    }
+}
+
+impl LanguageTransformer {
+    /// Builds a [`LanguageTransformer`] from a yomitan descriptor,
+    /// see [`LanguageTransformDescriptorFile::from_yomitan_json`].
+    pub fn from_yomitan_json(json: &str) -> Result<Self, DescriptorFileError> {
+        let descriptor =
+            LanguageTransformDescriptorFile::from_yomitan_json(json)?.into_descriptor()?;
+        let mut lt = LanguageTransformer::new();
+        lt.add_descriptor(&descriptor).context(AddDescriptorSnafu)?;
+        Ok(lt)
+    }
+}
+
+#[cfg(test)]
+mod descriptor_file_tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        en::en_transforms::ENGLISH_TRANSFORMS_DESCRIPTOR,
+        es::es_transforms::SPANISH_TRANSFORMS_DESCRIPTOR,
+        ja::ja_transforms::JAPANESE_TRANSFORMS_DESCRIPTOR, transformer::LanguageTransformer,
+    };
+
+    fn assert_same_transforms(
+        expected: &LanguageTransformDescriptor,
+        loaded: &LanguageTransformDescriptor,
+        inputs: &[&str],
+    ) {
+        let mut expected_lt = LanguageTransformer::new();
+        expected_lt.add_descriptor(expected).unwrap();
+        let mut loaded_lt = LanguageTransformer::new();
+        loaded_lt.add_descriptor(loaded).unwrap();
+        for input in inputs {
+            assert_eq!(expected_lt.transform(input), loaded_lt.transform(input));
+        }
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let file = LanguageTransformDescriptorFile::from(&*JAPANESE_TRANSFORMS_DESCRIPTOR);
+        let json = file.to_json().unwrap();
+        let loaded = LanguageTransformDescriptorFile::from_json(&json).unwrap();
+        assert_eq!(loaded, file);
+        let descriptor = loaded.into_descriptor().unwrap();
+        assert_same_transforms(
+            &JAPANESE_TRANSFORMS_DESCRIPTOR,
+            &descriptor,
+            &["愛しくありません", "流れて", "食べさせられた"],
+        );
+    }
+
+    #[test]
+    fn toml_round_trip() {
+        for (descriptor, inputs) in [
+            (
+                &*ENGLISH_TRANSFORMS_DESCRIPTOR,
+                ["going to walk", "looked up"],
+            ),
+            (&*SPANISH_TRANSFORMS_DESCRIPTOR, ["me despertar", "cuenten"]),
+        ] {
+            let file = LanguageTransformDescriptorFile::from(descriptor);
+            let toml = file.to_toml().unwrap();
+            let loaded = LanguageTransformDescriptorFile::from_toml(&toml).unwrap();
+            assert_eq!(loaded, file);
+            assert_same_transforms(descriptor, &loaded.into_descriptor().unwrap(), &inputs);
+        }
+    }
+
+    #[test]
+    fn minimal_toml() {
+        let toml = r#"
+language = "xx"
+
+[conditions.v]
+name = "Verb"
+is_dictionary_form = true
+
+[transforms.past]
+name = "past"
+
+[[transforms.past.rules]]
+type = "suffix"
+inflected = "ed"
+conditions_in = ["v"]
+conditions_out = ["v"]
+"#;
+        let descriptor = LanguageTransformDescriptorFile::from_toml(toml)
+            .unwrap()
+            .into_descriptor()
+            .unwrap();
+        let mut lt = LanguageTransformer::new();
+        lt.add_descriptor(&descriptor).unwrap();
+        assert!(lt.transform("walked").iter().any(|tt| tt.text == "walk"));
+    }
+
+    #[test]
+    fn missing_inflected() {
+        let json = r#"{ "language": "xx", "conditions": {}, "transforms": {
+            "past": { "name": "past", "rules": [{ "type": "suffix" }] } } }"#;
+        let file = LanguageTransformDescriptorFile::from_json(json).unwrap();
+        assert!(matches!(
+            file.into_descriptor(),
+            Err(DescriptorFileError::MissingField {
+                field: "inflected",
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn invalid_regex() {
+        let json = r#"{ "language": "xx", "conditions": {}, "transforms": {
+            "past": { "name": "past", "rules": [{ "type": "suffix", "inflected": "a(" }] } } }"#;
+        let file = LanguageTransformDescriptorFile::from_json(json).unwrap();
+        assert!(matches!(
+            file.into_descriptor(),
+            Err(DescriptorFileError::InvalidRegex { index: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn yomitan_json() {
+        let json = r#"{
+            "language": "en",
+            "conditions": {
+                "v": { "name": "Verb", "isDictionaryForm": true, "subConditions": ["v_phr"] },
+                "v_phr": { "name": "Phrasal verb", "isDictionaryForm": true },
+                "adj": { "name": "Adjective", "isDictionaryForm": true }
+            },
+            "transforms": {
+                "past": {
+                    "name": "past",
+                    "description": "Simple past tense of a verb",
+                    "rules": [
+                        { "type": "suffix", "isInflected": "/ed$/", "deinflected": "", "conditionsIn": ["v"], "conditionsOut": ["v"] },
+                        { "type": "suffix", "isInflected": { "rgx": "/ied$/" }, "deinflected": "y", "conditionsIn": ["v"], "conditionsOut": ["v"] }
+                    ]
+                },
+                "un-": {
+                    "name": "un-",
+                    "rules": [
+                        { "type": "prefix", "isInflected": { "source": "^un", "flags": "" }, "deinflected": "", "conditionsIn": ["adj", "v"], "conditionsOut": ["adj", "v"] }
+                    ]
+                },
+                "irregular": {
+                    "name": "past",
+                    "rules": [
+                        { "type": "wholeWord", "isInflected": "^went$", "deinflected": "go", "conditionsIn": ["v"], "conditionsOut": ["v"] }
+                    ]
+                }
+            }
+        }"#;
+        let lt = LanguageTransformer::from_yomitan_json(json).unwrap();
+        let texts = |source: &str| -> Vec<String> {
+            lt.transform(source).into_iter().map(|tt| tt.text).collect()
+        };
+        assert!(texts("unwalked").contains(&"walk".to_string()));
+        assert!(texts("tried").contains(&"try".to_string()));
+        assert!(texts("went").contains(&"go".to_string()));
+    }
+
+    #[test]
+    fn yomitan_json_missing_deinflected() {
+        // what `JSON.stringify` gives for yomitan's `prefixInflection` & `wholeWordInflection`
+        for rule in [
+            r#"{ "type": "prefix", "isInflected": "/^un/", "conditionsIn": [], "conditionsOut": [] }"#,
+            r#"{ "type": "wholeWord", "isInflected": "/^went$/", "conditionsIn": ["v"], "conditionsOut": ["v"] }"#,
+        ] {
+            let json = format!(
+                r#"{{ "language": "xx", "conditions": {{}}, "transforms": {{
+                "t": {{ "name": "t", "rules": [{rule}] }} }} }}"#
+            );
+            assert!(matches!(
+                LanguageTransformDescriptorFile::from_yomitan_json(&json),
+                Err(DescriptorFileError::MissingField {
+                    field: "deinflected",
+                    ..
+                })
+            ));
+        }
+    }
+
+    #[test]
+    fn yomitan_json_regex_pattern() {
+        let json = r#"{ "language": "xx", "conditions": { "v": { "name": "Verb", "isDictionaryForm": true } },
+            "transforms": { "past": { "name": "past", "rules": [
+                { "type": "suffix", "isInflected": "/ie?d$/", "deinflected": "y", "conditionsIn": ["v"], "conditionsOut": ["v"] }
+            ] } } }"#;
+        let file = LanguageTransformDescriptorFile::from_yomitan_json(json).unwrap();
+        let rule = &file.transforms["past"].rules[0];
+        assert_eq!(rule.rule_type, RuleType::Other);
+        assert_eq!(rule.is_inflected.as_deref(), Some("ie?d$"));
+        let mut lt = LanguageTransformer::new();
+        lt.add_descriptor(&file.into_descriptor().unwrap()).unwrap();
+        assert!(lt.transform("tried").iter().any(|tt| tt.text == "try"));
+        assert!(lt.transform("trid").iter().any(|tt| tt.text == "try"));
+
+        let json = json.replace(
+            r#""suffix", "isInflected": "/ie?d$/""#,
+            r#""prefix", "isInflected": "/^(un)?/""#,
+        );
+        assert!(matches!(
+            LanguageTransformDescriptorFile::from_yomitan_json(&json),
+            Err(DescriptorFileError::InvalidPattern { .. })
+        ));
+    }
+
+    #[test]
+    fn yomitan_json_unserialized_regex() {
+        let json = r#"{ "language": "xx", "conditions": {}, "transforms": {
+            "past": { "name": "past", "rules": [{ "type": "suffix", "isInflected": {} }] } } }"#;
+        assert!(matches!(
+            LanguageTransformDescriptorFile::from_yomitan_json(json),
+            Err(DescriptorFileError::Json { .. })
+        ));
+    }
+}
@@ -3,22 +3,33 @@ use std::{collections::HashMap, sync::LazyLock};
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
+    ar::{ar_transforms::ARABIC_TRANSFORMS_DESCRIPTOR, arabic::is_string_partially_arabic},
     en::en_transforms::ENGLISH_TRANSFORMS_DESCRIPTOR,
     es::es_transforms::SPANISH_TRANSFORMS_DESCRIPTOR,
+    fa::{fa_transforms::PERSIAN_TRANSFORMS_DESCRIPTOR, persian::is_string_partially_persian},
+    it::it_transforms::ITALIAN_TRANSFORMS_DESCRIPTOR,
     ja::{
         self, ja_transforms::JAPANESE_TRANSFORMS_DESCRIPTOR, japanese::is_string_partially_japanese,
     },
+    ko::{ko_transforms::KOREAN_TRANSFORMS_DESCRIPTOR, korean::is_string_partially_korean},
     language_d::{
         AnyTextProcessor, BidirectionalConversionPreProcessor, ReadingNormalizer, TextProcessor,
         TextProcessorWithId,
     },
+    ru::{ru_transforms::RUSSIAN_TRANSFORMS_DESCRIPTOR, russian::is_string_partially_russian},
     text_preprocessors::{
-        ALPHABETIC_TO_HIRAGANA, ALPHANUMERIC_WIDTH_VARIANTS, COLLAPSE_EMPHATIC_SEQUENCES,
-        CONVERT_HALF_WIDTH_CHARACTERS, CONVERT_HIRAGANA_TO_KATAKANA,
-        NORMALIZE_CJK_COMPATIBILITY_CHARACTERS, NORMALIZE_COMBINING_CHARACTERS, STANDARDIZE_KANJI,
+        ADD_HAMZA_BOTTOM, ADD_HAMZA_TOP, ALPHABETIC_TO_HIRAGANA, ALPHANUMERIC_WIDTH_VARIANTS,
+        COLLAPSE_EMPHATIC_SEQUENCES, CONVERT_ALEF_MAKSURA_TO_YEH, CONVERT_CHINESE_VARIANTS,
+        CONVERT_HALF_WIDTH_CHARACTERS, CONVERT_HIRAGANA_TO_KATAKANA, CONVERT_PERSIAN_CHARACTERS,
+        CONVERT_PINYIN_TONES, DISASSEMBLE_HANGUL, NORMALIZE_ALEF_VARIANTS,
+        NORMALIZE_CJK_COMPATIBILITY_CHARACTERS, NORMALIZE_COMBINING_CHARACTERS, REASSEMBLE_HANGUL,
+        REMOVE_ARABIC_DIACRITICS, REMOVE_PINYIN_TONES, REMOVE_RUSSIAN_DIACRITICS, REMOVE_TATWEEL,
+        REMOVE_ZERO_WIDTH_NON_JOINER, STANDARDIZE_KANJI,
     },
     text_processors::{CAPITALIZE_FIRST_LETTER, DECAPITALIZE, NORMALIZE_RADICAL_CHARACTERS},
     transformer::LanguageTransformDescriptor,
+    yue::cantonese::{is_string_partially_cantonese, normalize_jyutping},
+    zh::chinese::{is_string_partially_chinese, normalize_pinyin},
 };
 
 pub fn collect_graphemes(text: &str) -> Vec<&str> {
@@ -217,5 +228,217 @@ pub static LANGUAGE_DESCRIPTOR_MAP: LazyLock<IndexMap<&str, LanguageDescriptor>>
                     language_transforms: Some(&*SPANISH_TRANSFORMS_DESCRIPTOR),
                 },
             ),
+            (
+                "it",
+                LanguageDescriptor {
+                    iso: "it",
+                    iso639_3: "ita",
+                    name: "Italian",
+                    example_text: "leggere",
+                    is_text_lookup_worthy: None,
+                    reading_normalizer: None,
+                    text_processors: PreAndPostProcessors {
+                        pre: vec![
+                            TextProcessorWithId {
+                                id: "decapitalize",
+                                processor: DECAPITALIZE,
+                            },
+                            TextProcessorWithId {
+                                id: "capitalize_first_letter",
+                                processor: CAPITALIZE_FIRST_LETTER,
+                            },
+                        ],
+                        post: vec![],
+                    },
+                    language_transforms: Some(&*ITALIAN_TRANSFORMS_DESCRIPTOR),
+                },
+            ),
+            (
+                "ar",
+                LanguageDescriptor {
+                    iso: "ar",
+                    iso639_3: "ara",
+                    name: "Arabic",
+                    example_text: "قَرَأَ",
+                    is_text_lookup_worthy: Some(is_string_partially_arabic),
+                    reading_normalizer: None,
+                    text_processors: PreAndPostProcessors {
+                        pre: vec![
+                            TextProcessorWithId {
+                                id: "remove_arabic_diacritics",
+                                processor: REMOVE_ARABIC_DIACRITICS,
+                            },
+                            TextProcessorWithId {
+                                id: "remove_tatweel",
+                                processor: REMOVE_TATWEEL,
+                            },
+                            TextProcessorWithId {
+                                id: "normalize_alef_variants",
+                                processor: NORMALIZE_ALEF_VARIANTS,
+                            },
+                            TextProcessorWithId {
+                                id: "add_hamza_top",
+                                processor: ADD_HAMZA_TOP,
+                            },
+                            TextProcessorWithId {
+                                id: "add_hamza_bottom",
+                                processor: ADD_HAMZA_BOTTOM,
+                            },
+                            TextProcessorWithId {
+                                id: "convert_alef_maksura_to_yeh",
+                                processor: CONVERT_ALEF_MAKSURA_TO_YEH,
+                            },
+                        ],
+                        post: vec![],
+                    },
+                    language_transforms: Some(&*ARABIC_TRANSFORMS_DESCRIPTOR),
+                },
+            ),
+            (
+                "fa",
+                LanguageDescriptor {
+                    iso: "fa",
+                    iso639_3: "fas",
+                    name: "Persian",
+                    example_text: "خواندن",
+                    is_text_lookup_worthy: Some(is_string_partially_persian),
+                    reading_normalizer: None,
+                    text_processors: PreAndPostProcessors {
+                        pre: vec![
+                            TextProcessorWithId {
+                                id: "remove_arabic_diacritics",
+                                processor: REMOVE_ARABIC_DIACRITICS,
+                            },
+                            TextProcessorWithId {
+                                id: "remove_tatweel",
+                                processor: REMOVE_TATWEEL,
+                            },
+                            TextProcessorWithId {
+                                id: "convert_persian_characters",
+                                processor: CONVERT_PERSIAN_CHARACTERS,
+                            },
+                            TextProcessorWithId {
+                                id: "remove_zero_width_non_joiner",
+                                processor: REMOVE_ZERO_WIDTH_NON_JOINER,
+                            },
+                        ],
+                        post: vec![],
+                    },
+                    language_transforms: Some(&*PERSIAN_TRANSFORMS_DESCRIPTOR),
+                },
+            ),
+            (
+                "ru",
+                LanguageDescriptor {
+                    iso: "ru",
+                    iso639_3: "rus",
+                    name: "Russian",
+                    example_text: "читать",
+                    is_text_lookup_worthy: Some(is_string_partially_russian),
+                    reading_normalizer: None,
+                    text_processors: PreAndPostProcessors {
+                        pre: vec![
+                            TextProcessorWithId {
+                                id: "decapitalize",
+                                processor: DECAPITALIZE,
+                            },
+                            TextProcessorWithId {
+                                id: "remove_russian_diacritics",
+                                processor: REMOVE_RUSSIAN_DIACRITICS,
+                            },
+                        ],
+                        post: vec![],
+                    },
+                    language_transforms: Some(&*RUSSIAN_TRANSFORMS_DESCRIPTOR),
+                },
+            ),
+            (
+                "ko",
+                LanguageDescriptor {
+                    iso: "ko",
+                    iso639_3: "kor",
+                    name: "Korean",
+                    example_text: "읽어",
+                    is_text_lookup_worthy: Some(is_string_partially_korean),
+                    reading_normalizer: None,
+                    text_processors: PreAndPostProcessors {
+                        pre: vec![TextProcessorWithId {
+                            id: "disassemble_hangul",
+                            processor: DISASSEMBLE_HANGUL,
+                        }],
+                        post: vec![TextProcessorWithId {
+                            id: "reassemble_hangul",
+                            processor: REASSEMBLE_HANGUL,
+                        }],
+                    },
+                    language_transforms: Some(&*KOREAN_TRANSFORMS_DESCRIPTOR),
+                },
+            ),
+            (
+                "zh",
+                LanguageDescriptor {
+                    iso: "zh",
+                    iso639_3: "zho",
+                    name: "Chinese",
+                    example_text: "读",
+                    is_text_lookup_worthy: Some(is_string_partially_chinese),
+                    reading_normalizer: Some(normalize_pinyin),
+                    text_processors: PreAndPostProcessors {
+                        pre: vec![
+                            TextProcessorWithId {
+                                id: "normalize_radical_characters",
+                                processor: NORMALIZE_RADICAL_CHARACTERS,
+                            },
+                            TextProcessorWithId {
+                                id: "alphanumeric_width_variants",
+                                processor: ALPHANUMERIC_WIDTH_VARIANTS,
+                            },
+                            TextProcessorWithId {
+                                id: "convert_chinese_variants",
+                                processor: CONVERT_CHINESE_VARIANTS,
+                            },
+                            TextProcessorWithId {
+                                id: "convert_pinyin_tones",
+                                processor: CONVERT_PINYIN_TONES,
+                            },
+                            TextProcessorWithId {
+                                id: "remove_pinyin_tones",
+                                processor: REMOVE_PINYIN_TONES,
+                            },
+                        ],
+                        post: vec![],
+                    },
+                    language_transforms: None,
+                },
+            ),
+            (
+                "yue",
+                LanguageDescriptor {
+                    iso: "yue",
+                    iso639_3: "yue",
+                    name: "Cantonese",
+                    example_text: "讀",
+                    is_text_lookup_worthy: Some(is_string_partially_cantonese),
+                    reading_normalizer: Some(normalize_jyutping),
+                    text_processors: PreAndPostProcessors {
+                        pre: vec![
+                            TextProcessorWithId {
+                                id: "normalize_radical_characters",
+                                processor: NORMALIZE_RADICAL_CHARACTERS,
+                            },
+                            TextProcessorWithId {
+                                id: "alphanumeric_width_variants",
+                                processor: ALPHANUMERIC_WIDTH_VARIANTS,
+                            },
+                            TextProcessorWithId {
+                                id: "convert_chinese_variants",
+                                processor: CONVERT_CHINESE_VARIANTS,
+                            },
+                        ],
+                        post: vec![],
+                    },
+                    language_transforms: None,
+                },
+            ),
         ])
     });
@@ -760,6 +760,7 @@ static EN_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("ves", "f", &["np"], &["ns"], RuleType::Suffix),
                 ],
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -772,6 +773,7 @@ static EN_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("s'", "s", &["n"], &["n"], RuleType::Suffix),
                 ],
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -788,6 +790,7 @@ static EN_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     ))
                     .collect(),
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -804,6 +807,7 @@ static EN_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     ))
                     .collect(),
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -820,6 +824,7 @@ static EN_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     ))
                     .collect(),
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -829,6 +834,7 @@ static EN_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                 description: Some("Phrasal verb with interposed object"),
                 rules: vec![PHRASAL_VERB_INTERPOSED_OBJECT_RULE.clone()],
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -838,6 +844,7 @@ static EN_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                 description: Some("Archaic form of a word"),
                 rules: vec![inflection("'d", "ed", &["v"], &["v"], RuleType::Suffix)],
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -851,6 +858,7 @@ static EN_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("ly", "le", &["adv"], &["adj"], RuleType::Suffix),
                 ],
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -871,6 +879,7 @@ static EN_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                         .map(|sr| sr.into()),
                 )
                 .collect(),
+                weight: None,
             },
         ),
         (
@@ -891,6 +900,7 @@ static EN_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                 )
                 .collect(),
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -900,6 +910,7 @@ static EN_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                 description: Some("Dropped g in -ing form of a verb"),
                 rules: vec![inflection("in'", "ing", &["v"], &["v"], RuleType::Suffix)],
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -919,6 +930,7 @@ static EN_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                 )
                 .collect(),
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -934,6 +946,7 @@ static EN_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     RuleType::Prefix,
                 )],
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -949,6 +962,7 @@ static EN_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     RuleType::Prefix,
                 )],
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -958,6 +972,7 @@ static EN_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                 description: Some("Will-future tense of a verb"),
                 rules: vec![inflection("will ", "", &["v"], &["v"], RuleType::Prefix)],
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -970,6 +985,7 @@ static EN_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("do not ", "", &["v"], &["v"], RuleType::Prefix),
                 ],
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -990,6 +1006,7 @@ static EN_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                 )
                 .collect(),
                 i18n: None,
+                weight: None,
             },
         ),
     ]))
@@ -1111,7 +1128,7 @@ pub(crate) mod entransforms {
         let mut lt = LanguageTransformer::new();
         lt.add_descriptor(&ENGLISH_TRANSFORMS_DESCRIPTOR).unwrap();
 
-        for (_, test) in EN_TRANSFORM_TESTS.iter().enumerate() {
+        for test in EN_TRANSFORM_TESTS.iter() {
             let term = test.term;
             for case in &test.sources {
                 let source = case.inner;
@@ -147,6 +147,7 @@ static ES_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                 }))
                 .collect(),
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -182,6 +183,7 @@ static ES_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                 }))
                 .collect(),
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -318,6 +320,7 @@ static ES_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("han", "haber", &["v"], &["v"], RuleType::WholeWord),
                 ],
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -451,6 +454,7 @@ static ES_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("anduvieron", "andar", &["v"], &["v"], RuleType::WholeWord),
                 ],
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -511,6 +515,7 @@ static ES_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("veían", "ver", &["v"], &["v"], RuleType::WholeWord),
                 ],
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -538,6 +543,7 @@ static ES_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("yendo", "ir", &["v"], &["v"], RuleType::WholeWord),
                 ],
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -617,6 +623,7 @@ static ES_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("áis", "ir", &["v_ir"], &["v_ir"], RuleType::Suffix),
                 ],
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -700,6 +707,7 @@ static ES_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("sabrían", "saber", &["v"], &["v"], RuleType::WholeWord),
                 ],
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -760,6 +768,7 @@ static ES_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("vendrán", "venir", &["v"], &["v"], RuleType::Suffix),
                 ],
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -872,6 +881,7 @@ static ES_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("sepan", "saber", &["v"], &["v"], RuleType::WholeWord),
                 ],
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -948,6 +958,7 @@ static ES_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("fuesen", "ir", &["v"], &["v"], RuleType::WholeWord),
                 ],
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -971,6 +982,7 @@ static ES_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("vuelto", "volver", &["adj"], &["v"], RuleType::WholeWord),
                 ],
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -990,6 +1002,7 @@ static ES_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("irse", "ir", &["v_ir"], &["v_ir"], RuleType::Suffix),
                 ],
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -1013,6 +1026,7 @@ static ES_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("irnos", "irse", &["v_ir"], &["v_ir"], RuleType::Suffix),
                 ],
                 i18n: None,
+                weight: None,
             },
         ),
         (
@@ -1031,6 +1045,7 @@ static ES_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     conditions_out: &["v"],
                 }],
                 i18n: None,
+                weight: None,
             },
         ),
     ]))
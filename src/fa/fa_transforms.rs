@@ -0,0 +1,564 @@
+use indexmap::IndexMap;
+use std::sync::LazyLock;
+
+use crate::{
+    ja::ja_transforms::{LanguageTransformerTestCase, TransformTest},
+    transformer::{
+        Condition, ConditionMap, LanguageTransformDescriptor, Rule, RuleType, Transform,
+        TransformMap,
+    },
+    transforms::inflection,
+};
+
+/// Pronominal clitics: my, your, his/her, our, your (pl), their.
+const PRONOMINAL_CLITICS: [&str; 6] = ["م", "ت", "ش", "مان", "تان", "شان"];
+
+pub static PERSIAN_TRANSFORMS_DESCRIPTOR: LazyLock<LanguageTransformDescriptor> =
+    LazyLock::new(|| LanguageTransformDescriptor {
+        language: "fa",
+        conditions: &FA_CONDITIONS_MAP,
+        transforms: &FA_TRANSFORMS_MAP,
+    });
+
+pub static FA_CONDITIONS_MAP: LazyLock<ConditionMap> = LazyLock::new(|| {
+    ConditionMap(IndexMap::from([
+        (
+            "n",
+            Condition {
+                name: "Noun",
+                is_dictionary_form: true,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "adj",
+            Condition {
+                name: "Adjective",
+                is_dictionary_form: true,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v",
+            Condition {
+                name: "Verb",
+                is_dictionary_form: true,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "n_pl",
+            Condition {
+                name: "Plural noun",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "adj_cmp",
+            Condition {
+                name: "Comparative or superlative adjective",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "ez",
+            Condition {
+                name: "Word with ezafe",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "pron",
+            Condition {
+                name: "Word with pronominal clitic",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v_neg",
+            Condition {
+                name: "Negative verb",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v_prog",
+            Condition {
+                name: "Progressive verb",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v_subj",
+            Condition {
+                name: "Subjunctive or imperative verb",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v_end",
+            Condition {
+                name: "Verb with personal ending",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v_past",
+            Condition {
+                name: "Past stem",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+    ]))
+});
+
+/// Personal endings of the present tense. The present stem is only looked for in front of
+/// them, a bare stem (ie: the imperative بخر) would match any word.
+const PRESENT_ENDINGS: [&str; 6] = ["م", "ی", "د", "یم", "ید", "ند"];
+
+/// Rules removing a pronominal clitic.
+///
+/// After a silent ه the clitic is written with a zero-width non-joiner & alef (خانه‌ام),
+/// after ا / و with a ی (کتاب‌هایم, دانشجویم).
+fn pronominal_clitic_rules() -> Vec<Rule> {
+    let mut rules = Vec::new();
+    for clitic in PRONOMINAL_CLITICS {
+        rules.push(inflection(
+            clitic,
+            "",
+            &["pron"],
+            &["n", "adj", "n_pl", "v_end", "v_past"],
+            RuleType::Suffix,
+        ));
+        rules.push(inflection(
+            &format!("\u{200c}ا{clitic}"),
+            "",
+            &["pron"],
+            &["n", "adj", "n_pl"],
+            RuleType::Suffix,
+        ));
+        rules.push(inflection(
+            &format!("ای{clitic}"),
+            "ا",
+            &["pron"],
+            &["n", "adj", "n_pl"],
+            RuleType::Suffix,
+        ));
+        rules.push(inflection(
+            &format!("وی{clitic}"),
+            "و",
+            &["pron"],
+            &["n", "adj"],
+            RuleType::Suffix,
+        ));
+    }
+    rules
+}
+
+pub static FA_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
+    TransformMap(IndexMap::from([
+        (
+            "plural",
+            Transform {
+                name: "plural",
+                description: Some("Plural form of a noun (ها / ان)"),
+                rules: vec![
+                    inflection("\u{200c}ها", "", &["n_pl"], &["n"], RuleType::Suffix),
+                    inflection("ها", "", &["n_pl"], &["n"], RuleType::Suffix),
+                    inflection("ان", "", &["n_pl"], &["n"], RuleType::Suffix),
+                    inflection("یان", "", &["n_pl"], &["n"], RuleType::Suffix),
+                    inflection("گان", "ه", &["n_pl"], &["n"], RuleType::Suffix),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "comparative",
+            Transform {
+                name: "comparative",
+                description: Some("Comparative form of an adjective (تر)"),
+                rules: vec![
+                    inflection("\u{200c}تر", "", &["adj_cmp"], &["adj"], RuleType::Suffix),
+                    inflection("تر", "", &["adj_cmp"], &["adj"], RuleType::Suffix),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "superlative",
+            Transform {
+                name: "superlative",
+                description: Some("Superlative form of an adjective (ترین)"),
+                rules: vec![
+                    inflection("\u{200c}ترین", "", &["adj_cmp"], &["adj"], RuleType::Suffix),
+                    inflection("ترین", "", &["adj_cmp"], &["adj"], RuleType::Suffix),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "ezafe",
+            Transform {
+                name: "ezafe",
+                description: Some("Ezafe linking a word to its modifier (کتابِ من, خانه‌ی من)"),
+                rules: vec![
+                    inflection(
+                        "\u{200c}ی",
+                        "",
+                        &["ez"],
+                        &["n", "adj", "n_pl", "adj_cmp"],
+                        RuleType::Suffix,
+                    ),
+                    inflection("ۀ", "ه", &["ez"], &["n", "adj"], RuleType::Suffix),
+                    inflection("ای", "ا", &["ez"], &["n", "adj", "n_pl"], RuleType::Suffix),
+                    inflection("وی", "و", &["ez"], &["n", "adj"], RuleType::Suffix),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "pronominal clitic",
+            Transform {
+                name: "pronominal clitic",
+                description: Some("Attached pronoun (کتابم my book, دیدمش I saw him)"),
+                rules: pronominal_clitic_rules(),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "negative",
+            Transform {
+                name: "negative",
+                description: Some("Negative prefix ن"),
+                rules: vec![inflection(
+                    "ن",
+                    "",
+                    &["v_neg"],
+                    &["v_prog", "v_end", "v_past"],
+                    RuleType::Prefix,
+                )],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "progressive",
+            Transform {
+                name: "progressive",
+                description: Some("Progressive prefix می of the present & past continuous"),
+                rules: vec![
+                    inflection(
+                        "می\u{200c}",
+                        "",
+                        &["v_prog"],
+                        &["v_end", "v_past"],
+                        RuleType::Prefix,
+                    ),
+                    inflection(
+                        "می",
+                        "",
+                        &["v_prog"],
+                        &["v_end", "v_past"],
+                        RuleType::Prefix,
+                    ),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "subjunctive",
+            Transform {
+                name: "subjunctive",
+                description: Some("Subjunctive & imperative prefix ب"),
+                rules: vec![inflection(
+                    "ب",
+                    "",
+                    &["v_subj"],
+                    &["v_end"],
+                    RuleType::Prefix,
+                )],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "personal ending",
+            Transform {
+                name: "personal ending",
+                description: Some("Personal ending of a verb"),
+                rules: vec![
+                    inflection("م", "", &["v_end"], &["v_past"], RuleType::Suffix),
+                    inflection("ی", "", &["v_end"], &["v_past"], RuleType::Suffix),
+                    inflection(
+                        "یم",
+                        "",
+                        &["v_end"],
+                        &["v_past"],
+                        RuleType::Suffix,
+                    ),
+                    inflection(
+                        "ید",
+                        "",
+                        &["v_end"],
+                        &["v_past"],
+                        RuleType::Suffix,
+                    ),
+                    inflection(
+                        "ند",
+                        "",
+                        &["v_end"],
+                        &["v_past"],
+                        RuleType::Suffix,
+                    ),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "past",
+            Transform {
+                name: "past",
+                description: Some("Past stem of a verb, its infinitive without ن"),
+                rules: vec![
+                    inflection("ت", "تن", &["v_past"], &["v"], RuleType::Suffix),
+                    inflection("د", "دن", &["v_past"], &["v"], RuleType::Suffix),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "present",
+            Transform {
+                name: "present",
+                description: Some(
+                    "Personal ending on the present stem of a regular verb (خرم from خریدن, خوانم from خواندن)",
+                ),
+                rules: PRESENT_ENDINGS
+                    .iter()
+                    .flat_map(|ending| {
+                        ["یدن", "دن"].map(|infinitive| {
+                            inflection(ending, infinitive, &["v_end"], &["v"], RuleType::Suffix)
+                        })
+                    })
+                    .collect(),
+                i18n: None,
+                weight: None,
+            },
+        ),
+    ]))
+});
+
+pub(crate) static FA_TRANSFORM_TESTS: LazyLock<[&[TransformTest]; 3]> =
+    LazyLock::new(|| [&*FA_NOUN_TESTS, &*FA_ADJECTIVE_TESTS, &*FA_VERB_TESTS]);
+
+pub(crate) static FA_NOUN_TESTS: LazyLock<[TransformTest; 3]> = LazyLock::new(|| {
+    [
+        TransformTest {
+            term: "کتاب",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "کتاب‌ها",
+                    rule: "n",
+                    reasons: vec!["plural"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "کتابها",
+                    rule: "n",
+                    reasons: vec!["plural"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "کتاب‌های",
+                    rule: "n",
+                    reasons: vec!["plural", "ezafe"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "کتابم",
+                    rule: "n",
+                    reasons: vec!["pronominal clitic"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "کتاب‌هایشان",
+                    rule: "n",
+                    reasons: vec!["plural", "pronominal clitic"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "خانه",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "خانه‌ام",
+                    rule: "n",
+                    reasons: vec!["pronominal clitic"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "خانه‌ی",
+                    rule: "n",
+                    reasons: vec!["ezafe"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "دانشجو",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "دانشجویان",
+                    rule: "n",
+                    reasons: vec!["plural"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "دانشجوی",
+                    rule: "n",
+                    reasons: vec!["ezafe"],
+                },
+            ],
+        },
+    ]
+});
+
+pub(crate) static FA_ADJECTIVE_TESTS: LazyLock<[TransformTest; 1]> = LazyLock::new(|| {
+    [TransformTest {
+        term: "بزرگ",
+        sources: vec![
+            LanguageTransformerTestCase {
+                inner: "بزرگتر",
+                rule: "adj",
+                reasons: vec!["comparative"],
+            },
+            LanguageTransformerTestCase {
+                inner: "بزرگ‌ترین",
+                rule: "adj",
+                reasons: vec!["superlative"],
+            },
+        ],
+    }]
+});
+
+pub(crate) static FA_VERB_TESTS: LazyLock<[TransformTest; 3]> = LazyLock::new(|| {
+    [
+        TransformTest {
+            term: "خریدن",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "خرید",
+                    rule: "v",
+                    reasons: vec!["past"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "خریدند",
+                    rule: "v",
+                    reasons: vec!["past", "personal ending"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "می‌خریدند",
+                    rule: "v",
+                    reasons: vec!["past", "personal ending", "progressive"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "می‌خرم",
+                    rule: "v",
+                    reasons: vec!["present", "progressive"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "نمیخرند",
+                    rule: "v",
+                    reasons: vec!["present", "progressive", "negative"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "بخرم",
+                    rule: "v",
+                    reasons: vec!["present", "subjunctive"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "خواندن",
+            sources: vec![LanguageTransformerTestCase {
+                inner: "می‌خوانیم",
+                rule: "v",
+                reasons: vec!["present", "progressive"],
+            }],
+        },
+        TransformTest {
+            term: "نوشتن",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "نوشتیم",
+                    rule: "v",
+                    reasons: vec!["past", "personal ending"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "ننوشتی",
+                    rule: "v",
+                    reasons: vec!["past", "personal ending", "negative"],
+                },
+            ],
+        },
+    ]
+});
+
+#[cfg(test)]
+mod fatransforms {
+    use crate::{
+        fa::fa_transforms::{FA_TRANSFORM_TESTS, PERSIAN_TRANSFORMS_DESCRIPTOR},
+        ja::ja_transforms::has_term_reasons,
+        transformer::LanguageTransformer,
+    };
+
+    #[test]
+    fn transforms() {
+        let mut lt = LanguageTransformer::new();
+        lt.add_descriptor(&PERSIAN_TRANSFORMS_DESCRIPTOR).unwrap();
+
+        for test_vec in FA_TRANSFORM_TESTS.into_iter() {
+            for test in test_vec {
+                let term = test.term;
+                for case in &test.sources {
+                    let source = case.inner;
+                    let rule = case.rule;
+                    let expected_reasons = &case.reasons;
+
+                    let result =
+                        has_term_reasons(&lt, source, term, Some(rule), Some(expected_reasons));
+                    if let Err(e) = result {
+                        panic!("Failed: {e}");
+                    }
+                }
+            }
+        }
+        // a word without a personal ending isn't taken for a present stem
+        assert!(lt
+            .transform("کتاب")
+            .iter()
+            .all(|tt| !tt.text.ends_with("دن")));
+    }
+}
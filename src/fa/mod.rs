@@ -0,0 +1,2 @@
+pub mod fa_transforms;
+pub mod persian;
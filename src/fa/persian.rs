@@ -0,0 +1,80 @@
+use crate::arabic_utils::is_string_partially_arabic_script;
+
+pub const ARABIC_YEH: char = 'ي';
+pub const ARABIC_ALEF_MAKSURA: char = 'ى';
+pub const ARABIC_KAF: char = 'ك';
+pub const PERSIAN_YEH: char = 'ی';
+pub const PERSIAN_KEHEH: char = 'ک';
+/// Written between the parts of a word that shouldn't be joined (ie: `می‌روم`, `کتاب‌ها`).
+pub const ZERO_WIDTH_NON_JOINER: char = '\u{200c}';
+
+/// Checks if a string contains at least one Persian character.
+pub fn is_string_partially_persian(s: &str) -> bool {
+    is_string_partially_arabic_script(s)
+}
+
+/// Writes Arabic yeh & kaf as their Persian forms: `كتاب علي` -> `کتاب علی`.
+pub fn convert_arabic_to_persian_characters(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            ARABIC_YEH | ARABIC_ALEF_MAKSURA => PERSIAN_YEH,
+            ARABIC_KAF => PERSIAN_KEHEH,
+            c => c,
+        })
+        .collect()
+}
+
+/// Writes Persian yeh & keheh as their Arabic forms: `کتاب علی` -> `كتاب علي`.
+pub fn convert_persian_to_arabic_characters(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            PERSIAN_YEH => ARABIC_YEH,
+            PERSIAN_KEHEH => ARABIC_KAF,
+            c => c,
+        })
+        .collect()
+}
+
+/// `می‌روم` -> `میروم`
+pub fn remove_zero_width_non_joiner(s: &str) -> String {
+    s.chars().filter(|&c| c != ZERO_WIDTH_NON_JOINER).collect()
+}
+
+#[cfg(test)]
+mod fa_tests {
+    use super::*;
+
+    #[test]
+    fn partially_persian() {
+        assert!(is_string_partially_persian("کتاب"));
+        assert!(is_string_partially_persian("a کتاب"));
+        assert!(!is_string_partially_persian("ketab"));
+    }
+
+    #[test]
+    fn yeh_and_kaf() {
+        assert_eq!(convert_arabic_to_persian_characters("كتاب علي"), "کتاب علی");
+        assert_eq!(convert_arabic_to_persian_characters("مصطفى"), "مصطفی");
+        assert_eq!(convert_persian_to_arabic_characters("کتاب علی"), "كتاب علي");
+        assert_eq!(remove_zero_width_non_joiner("می\u{200c}روم"), "میروم");
+    }
+
+    #[test]
+    fn language_descriptor() {
+        use crate::languages::{get_all_language_text_processors, is_text_lookup_worthy};
+
+        assert!(is_text_lookup_worthy("می‌خوانم", "fa"));
+        assert!(!is_text_lookup_worthy("mikhanam", "fa"));
+        let processors = get_all_language_text_processors()
+            .into_iter()
+            .find(|p| p.iso == "fa")
+            .unwrap();
+        let text = processors
+            .pre
+            .iter()
+            .fold("مي‌خـوانَم".to_string(), |text, p| {
+                (p.processor.process)(&text, p.processor.options[1].clone())
+            });
+        assert_eq!(text, "میخوانم");
+    }
+}
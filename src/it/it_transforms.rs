@@ -0,0 +1,1035 @@
+use indexmap::IndexMap;
+use std::sync::LazyLock;
+
+use crate::{
+    ja::ja_transforms::{LanguageTransformerTestCase, TransformTest},
+    transformer::{
+        Condition, ConditionMap, LanguageTransformDescriptor, Rule, RuleType, Transform,
+        TransformMap,
+    },
+    transforms::inflection,
+};
+
+/// `(inflected, deinflected)` pairs.
+type Endings = &'static [(&'static str, &'static str)];
+
+fn suffix_rules(endings: Endings, conditions: &'static [&'static str]) -> Vec<Rule> {
+    endings
+        .iter()
+        .map(|&(inflected, deinflected)| {
+            inflection(
+                inflected,
+                deinflected,
+                conditions,
+                conditions,
+                RuleType::Suffix,
+            )
+        })
+        .collect()
+}
+
+fn irregular_rules(forms: &[&str], infinitive: &'static str) -> Vec<Rule> {
+    forms
+        .iter()
+        .map(|form| inflection(form, infinitive, &["v"], &["v"], RuleType::WholeWord))
+        .collect()
+}
+
+/// Future & conditional endings are added to the infinitive with -are turned into -er-,
+/// so `parlerò`, `cercherò` (cercare), `mangerò` (mangiare).
+fn future_rules(endings: &[&str]) -> Vec<Rule> {
+    const STEMS: [(&str, &str, &[&str]); 7] = [
+        ("er", "are", &["v_are"]),
+        ("cher", "care", &["v_are"]),
+        ("gher", "gare", &["v_are"]),
+        ("cer", "ciare", &["v_are"]),
+        ("ger", "giare", &["v_are"]),
+        ("er", "ere", &["v_ere"]),
+        ("ir", "ire", &["v_ire"]),
+    ];
+    endings
+        .iter()
+        .flat_map(|ending| {
+            STEMS.iter().map(move |&(stem, infinitive, conditions)| {
+                inflection(
+                    &format!("{stem}{ending}"),
+                    infinitive,
+                    conditions,
+                    conditions,
+                    RuleType::Suffix,
+                )
+            })
+        })
+        .collect()
+}
+
+const SIMPLE_PRONOUNS: [&str; 11] = [
+    "mi", "ti", "si", "ci", "vi", "lo", "la", "li", "le", "ne", "gli",
+];
+
+/// Clustered pronouns are written as one word: me + lo -> `melo`, glie + ne -> `gliene`.
+const CLUSTERED_PRONOUNS: [&str; 6] = ["me", "te", "se", "ce", "ve", "glie"];
+const CLUSTERED_OBJECTS: [&str; 5] = ["lo", "la", "li", "le", "ne"];
+
+/// Imperatives of a single syllable double the pronoun's consonant: `dimmi`, `fallo`.
+const MONOSYLLABIC_IMPERATIVES: [&str; 5] = ["di", "da", "fa", "sta", "va"];
+
+static ENCLITIC_PRONOUNS: LazyLock<Vec<String>> = LazyLock::new(|| {
+    SIMPLE_PRONOUNS
+        .iter()
+        .map(|p| p.to_string())
+        .chain(CLUSTERED_PRONOUNS.iter().flat_map(|p| {
+            CLUSTERED_OBJECTS
+                .iter()
+                .map(move |object| format!("{p}{object}"))
+        }))
+        .collect()
+});
+
+fn enclitic_rules() -> Vec<Rule> {
+    // (stem, deinflected, conditions_out): the infinitive loses its final -e (`parlarmi`),
+    // the gerund (`parlandogli`) and the imperative (`parlami`) are left as is.
+    const HOSTS: [(&str, &str, &[&str]); 9] = [
+        ("ar", "are", &["v_are"]),
+        ("er", "ere", &["v_ere"]),
+        ("ir", "ire", &["v_ire"]),
+        ("or", "orre", &["v"]),
+        ("ur", "urre", &["v"]),
+        ("ando", "ando", &["v_are"]),
+        ("endo", "endo", &["v_ere", "v_ire"]),
+        ("a", "a", &["v_imp"]),
+        ("i", "i", &["v_imp"]),
+    ];
+    let mut rules: Vec<Rule> = ENCLITIC_PRONOUNS
+        .iter()
+        .flat_map(|pronoun| {
+            HOSTS
+                .iter()
+                .map(move |&(stem, deinflected, conditions_out)| {
+                    inflection(
+                        &format!("{stem}{pronoun}"),
+                        deinflected,
+                        &["v"],
+                        conditions_out,
+                        RuleType::Suffix,
+                    )
+                })
+        })
+        .collect();
+    for imperative in MONOSYLLABIC_IMPERATIVES {
+        for pronoun in ENCLITIC_PRONOUNS.iter().filter(|p| !p.starts_with("gli")) {
+            let consonant = &pronoun[..1];
+            rules.push(inflection(
+                &format!("{imperative}{consonant}{pronoun}"),
+                imperative,
+                &["v"],
+                &["v_imp"],
+                RuleType::WholeWord,
+            ));
+        }
+    }
+    rules
+}
+
+pub static ITALIAN_TRANSFORMS_DESCRIPTOR: LazyLock<LanguageTransformDescriptor> =
+    LazyLock::new(|| LanguageTransformDescriptor {
+        language: "it",
+        conditions: &IT_CONDITIONS_MAP,
+        transforms: &IT_TRANSFORMS_MAP,
+    });
+
+pub static IT_CONDITIONS_MAP: LazyLock<ConditionMap> = LazyLock::new(|| {
+    ConditionMap(IndexMap::from([
+        (
+            "n",
+            Condition {
+                name: "Noun",
+                is_dictionary_form: true,
+                sub_conditions: Some(&["ns", "np"]),
+                i18n: None,
+            },
+        ),
+        (
+            "np",
+            Condition {
+                name: "Noun plural",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "ns",
+            Condition {
+                name: "Noun singular",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v",
+            Condition {
+                name: "Verb",
+                is_dictionary_form: true,
+                sub_conditions: Some(&["v_are", "v_ere", "v_ire"]),
+                i18n: None,
+            },
+        ),
+        (
+            "v_are",
+            Condition {
+                name: "-are verb",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v_ere",
+            Condition {
+                name: "-ere verb",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v_ire",
+            Condition {
+                name: "-ire verb",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v_imp",
+            Condition {
+                name: "Imperative",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "adj",
+            Condition {
+                name: "Adjective",
+                is_dictionary_form: true,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+    ]))
+});
+
+static IT_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
+    TransformMap(IndexMap::from([
+        (
+            "plural",
+            Transform {
+                name: "plural",
+                description: Some("Plural form of a noun or adjective"),
+                rules: vec![
+                    inflection("i", "o", &["np"], &["ns"], RuleType::Suffix),
+                    inflection("i", "e", &["np"], &["ns"], RuleType::Suffix),
+                    inflection("i", "a", &["np"], &["ns"], RuleType::Suffix),
+                    inflection("e", "a", &["np"], &["ns"], RuleType::Suffix),
+                    // the hard sound of -co/-go is kept with an h
+                    inflection("chi", "co", &["np"], &["ns"], RuleType::Suffix),
+                    inflection("ghi", "go", &["np"], &["ns"], RuleType::Suffix),
+                    inflection("che", "ca", &["np"], &["ns"], RuleType::Suffix),
+                    inflection("ghe", "ga", &["np"], &["ns"], RuleType::Suffix),
+                    // irregular
+                    inflection("uomini", "uomo", &["np"], &["ns"], RuleType::WholeWord),
+                    inflection("uova", "uovo", &["np"], &["ns"], RuleType::WholeWord),
+                    inflection("dita", "dito", &["np"], &["ns"], RuleType::WholeWord),
+                    inflection("mani", "mano", &["np"], &["ns"], RuleType::WholeWord),
+                    // adjectives
+                    inflection("i", "o", &["adj"], &["adj"], RuleType::Suffix),
+                    inflection("i", "e", &["adj"], &["adj"], RuleType::Suffix),
+                    inflection("e", "a", &["adj"], &["adj"], RuleType::Suffix),
+                    inflection("chi", "co", &["adj"], &["adj"], RuleType::Suffix),
+                    inflection("ghi", "go", &["adj"], &["adj"], RuleType::Suffix),
+                    inflection("che", "ca", &["adj"], &["adj"], RuleType::Suffix),
+                    inflection("ghe", "ga", &["adj"], &["adj"], RuleType::Suffix),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "feminine adjective",
+            Transform {
+                name: "feminine adjective",
+                description: Some("feminine form of an adjective"),
+                rules: vec![inflection("a", "o", &["adj"], &["adj"], RuleType::Suffix)],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "present indicative",
+            Transform {
+                name: "present indicative",
+                description: Some("Present indicative form of a verb"),
+                rules: [
+                    suffix_rules(
+                        &[
+                            ("o", "are"),
+                            ("i", "are"),
+                            ("a", "are"),
+                            ("iamo", "are"),
+                            ("ate", "are"),
+                            ("ano", "are"),
+                            // -care/-gare, -iare
+                            ("chi", "care"),
+                            ("chiamo", "care"),
+                            ("ghi", "gare"),
+                            ("ghiamo", "gare"),
+                            ("i", "iare"),
+                            ("iamo", "iare"),
+                        ],
+                        &["v_are"],
+                    ),
+                    suffix_rules(
+                        &[
+                            ("o", "ere"),
+                            ("i", "ere"),
+                            ("iamo", "ere"),
+                            ("ete", "ere"),
+                            ("ono", "ere"),
+                        ],
+                        &["v_ere"],
+                    ),
+                    suffix_rules(
+                        &[
+                            ("o", "ire"),
+                            ("i", "ire"),
+                            ("iamo", "ire"),
+                            ("ite", "ire"),
+                            ("ono", "ire"),
+                            // -isc- verbs (e.g. finire -> finisco)
+                            ("isco", "ire"),
+                            ("isci", "ire"),
+                            ("isce", "ire"),
+                            ("iscono", "ire"),
+                        ],
+                        &["v_ire"],
+                    ),
+                    // the infinitive itself ends in -e, so these must not apply to their own output
+                    vec![
+                        inflection("e", "ere", &[], &["v_ere"], RuleType::Suffix),
+                        inflection("e", "ire", &[], &["v_ire"], RuleType::Suffix),
+                    ],
+                    irregular_rules(&["sono", "sei", "è", "siamo", "siete"], "essere"),
+                    irregular_rules(&["ho", "hai", "ha", "abbiamo", "hanno"], "avere"),
+                    irregular_rules(
+                        &["faccio", "fai", "fa", "facciamo", "fate", "fanno"],
+                        "fare",
+                    ),
+                    irregular_rules(&["vado", "vai", "va", "vanno"], "andare"),
+                    irregular_rules(
+                        &["dico", "dici", "dice", "diciamo", "dite", "dicono"],
+                        "dire",
+                    ),
+                ]
+                .concat(),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "imperfect",
+            Transform {
+                name: "imperfect",
+                description: Some("Imperfect form of a verb"),
+                rules: [
+                    suffix_rules(
+                        &[
+                            ("avo", "are"),
+                            ("avi", "are"),
+                            ("ava", "are"),
+                            ("avamo", "are"),
+                            ("avate", "are"),
+                            ("avano", "are"),
+                        ],
+                        &["v_are"],
+                    ),
+                    suffix_rules(
+                        &[
+                            ("evo", "ere"),
+                            ("evi", "ere"),
+                            ("eva", "ere"),
+                            ("evamo", "ere"),
+                            ("evate", "ere"),
+                            ("evano", "ere"),
+                        ],
+                        &["v_ere"],
+                    ),
+                    suffix_rules(
+                        &[
+                            ("ivo", "ire"),
+                            ("ivi", "ire"),
+                            ("iva", "ire"),
+                            ("ivamo", "ire"),
+                            ("ivate", "ire"),
+                            ("ivano", "ire"),
+                        ],
+                        &["v_ire"],
+                    ),
+                    irregular_rules(
+                        &["ero", "eri", "era", "eravamo", "eravate", "erano"],
+                        "essere",
+                    ),
+                ]
+                .concat(),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "passato remoto",
+            Transform {
+                name: "passato remoto",
+                description: Some("Simple past form of a verb"),
+                rules: [
+                    suffix_rules(
+                        &[
+                            ("ai", "are"),
+                            ("asti", "are"),
+                            ("ò", "are"),
+                            ("ammo", "are"),
+                            ("aste", "are"),
+                            ("arono", "are"),
+                        ],
+                        &["v_are"],
+                    ),
+                    suffix_rules(
+                        &[
+                            ("ei", "ere"),
+                            ("etti", "ere"),
+                            ("esti", "ere"),
+                            ("é", "ere"),
+                            ("è", "ere"),
+                            ("ette", "ere"),
+                            ("emmo", "ere"),
+                            ("este", "ere"),
+                            ("erono", "ere"),
+                            ("ettero", "ere"),
+                        ],
+                        &["v_ere"],
+                    ),
+                    suffix_rules(
+                        &[
+                            ("ii", "ire"),
+                            ("isti", "ire"),
+                            ("ì", "ire"),
+                            ("immo", "ire"),
+                            ("iste", "ire"),
+                            ("irono", "ire"),
+                        ],
+                        &["v_ire"],
+                    ),
+                    irregular_rules(
+                        &["fui", "fosti", "fu", "fummo", "foste", "furono"],
+                        "essere",
+                    ),
+                    irregular_rules(&["ebbi", "ebbe", "ebbero"], "avere"),
+                ]
+                .concat(),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "future",
+            Transform {
+                name: "future",
+                description: Some("Future form of a verb"),
+                rules: [
+                    future_rules(&["ò", "ai", "à", "emo", "ete", "anno"]),
+                    irregular_rules(
+                        &["sarò", "sarai", "sarà", "saremo", "sarete", "saranno"],
+                        "essere",
+                    ),
+                    irregular_rules(
+                        &["avrò", "avrai", "avrà", "avremo", "avrete", "avranno"],
+                        "avere",
+                    ),
+                ]
+                .concat(),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "conditional",
+            Transform {
+                name: "conditional",
+                description: Some("Conditional form of a verb"),
+                rules: [
+                    future_rules(&["ei", "esti", "ebbe", "emmo", "este", "ebbero"]),
+                    irregular_rules(
+                        &[
+                            "sarei",
+                            "saresti",
+                            "sarebbe",
+                            "saremmo",
+                            "sareste",
+                            "sarebbero",
+                        ],
+                        "essere",
+                    ),
+                    irregular_rules(
+                        &[
+                            "avrei",
+                            "avresti",
+                            "avrebbe",
+                            "avremmo",
+                            "avreste",
+                            "avrebbero",
+                        ],
+                        "avere",
+                    ),
+                ]
+                .concat(),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "present subjunctive",
+            Transform {
+                name: "present subjunctive",
+                description: Some("Present subjunctive form of a verb"),
+                rules: [
+                    suffix_rules(
+                        &[
+                            ("i", "are"),
+                            ("iamo", "are"),
+                            ("iate", "are"),
+                            ("ino", "are"),
+                            ("chi", "care"),
+                            ("chino", "care"),
+                            ("ghi", "gare"),
+                            ("ghino", "gare"),
+                            ("i", "iare"),
+                            ("ino", "iare"),
+                        ],
+                        &["v_are"],
+                    ),
+                    suffix_rules(
+                        &[
+                            ("a", "ere"),
+                            ("iamo", "ere"),
+                            ("iate", "ere"),
+                            ("ano", "ere"),
+                        ],
+                        &["v_ere"],
+                    ),
+                    suffix_rules(
+                        &[
+                            ("a", "ire"),
+                            ("iamo", "ire"),
+                            ("iate", "ire"),
+                            ("ano", "ire"),
+                            ("isca", "ire"),
+                            ("iscano", "ire"),
+                        ],
+                        &["v_ire"],
+                    ),
+                    irregular_rules(&["sia", "siamo", "siate", "siano"], "essere"),
+                    irregular_rules(&["abbia", "abbiamo", "abbiate", "abbiano"], "avere"),
+                ]
+                .concat(),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "imperfect subjunctive",
+            Transform {
+                name: "imperfect subjunctive",
+                description: Some("Imperfect subjunctive form of a verb"),
+                rules: [
+                    suffix_rules(
+                        &[
+                            ("assi", "are"),
+                            ("asse", "are"),
+                            ("assimo", "are"),
+                            ("aste", "are"),
+                            ("assero", "are"),
+                        ],
+                        &["v_are"],
+                    ),
+                    suffix_rules(
+                        &[
+                            ("essi", "ere"),
+                            ("esse", "ere"),
+                            ("essimo", "ere"),
+                            ("este", "ere"),
+                            ("essero", "ere"),
+                        ],
+                        &["v_ere"],
+                    ),
+                    suffix_rules(
+                        &[
+                            ("issi", "ire"),
+                            ("isse", "ire"),
+                            ("issimo", "ire"),
+                            ("iste", "ire"),
+                            ("issero", "ire"),
+                        ],
+                        &["v_ire"],
+                    ),
+                    irregular_rules(&["fossi", "fosse", "fossimo", "foste", "fossero"], "essere"),
+                ]
+                .concat(),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "imperative",
+            Transform {
+                name: "imperative",
+                description: Some("Imperative form of a verb"),
+                // only the tu form, the others are the same as the present
+                rules: vec![
+                    inflection("a", "are", &["v_imp"], &["v_are"], RuleType::Suffix),
+                    inflection("i", "ere", &["v_imp"], &["v_ere"], RuleType::Suffix),
+                    inflection("i", "ire", &["v_imp"], &["v_ire"], RuleType::Suffix),
+                    inflection("isci", "ire", &["v_imp"], &["v_ire"], RuleType::Suffix),
+                    // irregular
+                    inflection("di", "dire", &["v_imp"], &["v"], RuleType::WholeWord),
+                    inflection("da", "dare", &["v_imp"], &["v"], RuleType::WholeWord),
+                    inflection("fa", "fare", &["v_imp"], &["v"], RuleType::WholeWord),
+                    inflection("sta", "stare", &["v_imp"], &["v"], RuleType::WholeWord),
+                    inflection("va", "andare", &["v_imp"], &["v"], RuleType::WholeWord),
+                    inflection("sii", "essere", &["v_imp"], &["v"], RuleType::WholeWord),
+                    inflection("abbi", "avere", &["v_imp"], &["v"], RuleType::WholeWord),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "gerund",
+            Transform {
+                name: "gerund",
+                description: Some("Gerund form of a verb"),
+                rules: vec![
+                    inflection("ando", "are", &["v_are"], &["v_are"], RuleType::Suffix),
+                    inflection("endo", "ere", &["v_ere"], &["v_ere"], RuleType::Suffix),
+                    inflection("endo", "ire", &["v_ire"], &["v_ire"], RuleType::Suffix),
+                    // irregular
+                    inflection("facendo", "fare", &["v"], &["v"], RuleType::WholeWord),
+                    inflection("dicendo", "dire", &["v"], &["v"], RuleType::WholeWord),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "past participle",
+            Transform {
+                name: "past participle",
+                description: Some("Past participle form of a verb"),
+                rules: vec![
+                    inflection("ato", "are", &["adj"], &["v_are"], RuleType::Suffix),
+                    inflection("uto", "ere", &["adj"], &["v_ere"], RuleType::Suffix),
+                    inflection("ito", "ire", &["adj"], &["v_ire"], RuleType::Suffix),
+                    // irregular
+                    inflection("aperto", "aprire", &["adj"], &["v"], RuleType::WholeWord),
+                    inflection("chiuso", "chiudere", &["adj"], &["v"], RuleType::WholeWord),
+                    inflection("detto", "dire", &["adj"], &["v"], RuleType::WholeWord),
+                    inflection("fatto", "fare", &["adj"], &["v"], RuleType::WholeWord),
+                    inflection("letto", "leggere", &["adj"], &["v"], RuleType::WholeWord),
+                    inflection("messo", "mettere", &["adj"], &["v"], RuleType::WholeWord),
+                    inflection("morto", "morire", &["adj"], &["v"], RuleType::WholeWord),
+                    inflection("nato", "nascere", &["adj"], &["v"], RuleType::WholeWord),
+                    inflection("preso", "prendere", &["adj"], &["v"], RuleType::WholeWord),
+                    inflection("rotto", "rompere", &["adj"], &["v"], RuleType::WholeWord),
+                    inflection("scritto", "scrivere", &["adj"], &["v"], RuleType::WholeWord),
+                    inflection("stato", "essere", &["adj"], &["v"], RuleType::WholeWord),
+                    inflection("visto", "vedere", &["adj"], &["v"], RuleType::WholeWord),
+                    inflection("vissuto", "vivere", &["adj"], &["v"], RuleType::WholeWord),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "present participle",
+            Transform {
+                name: "present participle",
+                description: Some("Present participle form of a verb"),
+                rules: vec![
+                    inflection("ante", "are", &["adj"], &["v_are"], RuleType::Suffix),
+                    inflection("ente", "ere", &["adj"], &["v_ere"], RuleType::Suffix),
+                    inflection("ente", "ire", &["adj"], &["v_ire"], RuleType::Suffix),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "enclitic pronoun",
+            Transform {
+                name: "enclitic pronoun",
+                description: Some("Verb with pronouns attached to its end"),
+                rules: enclitic_rules(),
+                i18n: None,
+                weight: None,
+            },
+        ),
+    ]))
+});
+
+pub(crate) static IT_TRANSFORM_TESTS: LazyLock<[&[TransformTest]; 4]> = LazyLock::new(|| {
+    [
+        &*IT_VERB_TESTS,
+        &*IT_NOUN_TESTS,
+        &*IT_ADJECTIVE_TESTS,
+        &*IT_ENCLITIC_TESTS,
+    ]
+});
+
+pub(crate) static IT_VERB_TESTS: LazyLock<[TransformTest; 6]> = LazyLock::new(|| {
+    [
+        TransformTest {
+            term: "parlare",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "parlo",
+                    rule: "v",
+                    reasons: vec!["present indicative"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "parlate",
+                    rule: "v",
+                    reasons: vec!["present indicative"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "parlavamo",
+                    rule: "v",
+                    reasons: vec!["imperfect"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "parlarono",
+                    rule: "v",
+                    reasons: vec!["passato remoto"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "parleremo",
+                    rule: "v",
+                    reasons: vec!["future"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "parlerebbe",
+                    rule: "v",
+                    reasons: vec!["conditional"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "parlino",
+                    rule: "v",
+                    reasons: vec!["present subjunctive"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "parlassero",
+                    rule: "v",
+                    reasons: vec!["imperfect subjunctive"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "parlando",
+                    rule: "v",
+                    reasons: vec!["gerund"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "parlante",
+                    rule: "v",
+                    reasons: vec!["present participle"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "credere",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "credono",
+                    rule: "v",
+                    reasons: vec!["present indicative"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "credevi",
+                    rule: "v",
+                    reasons: vec!["imperfect"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "credettero",
+                    rule: "v",
+                    reasons: vec!["passato remoto"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "crederà",
+                    rule: "v",
+                    reasons: vec!["future"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "creduto",
+                    rule: "v",
+                    reasons: vec!["past participle"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "dormire",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "dormite",
+                    rule: "v",
+                    reasons: vec!["present indicative"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "dormivano",
+                    rule: "v",
+                    reasons: vec!["imperfect"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "dormì",
+                    rule: "v",
+                    reasons: vec!["passato remoto"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "dormiresti",
+                    rule: "v",
+                    reasons: vec!["conditional"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "dormendo",
+                    rule: "v",
+                    reasons: vec!["gerund"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "finire",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "finisco",
+                    rule: "v",
+                    reasons: vec!["present indicative"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "finiscano",
+                    rule: "v",
+                    reasons: vec!["present subjunctive"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "finita",
+                    rule: "v",
+                    reasons: vec!["past participle", "feminine adjective"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "cercare",
+            sources: vec![LanguageTransformerTestCase {
+                inner: "cercherò",
+                rule: "v",
+                reasons: vec!["future"],
+            }],
+        },
+        TransformTest {
+            term: "essere",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "siamo",
+                    rule: "v",
+                    reasons: vec!["present indicative"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "sarebbe",
+                    rule: "v",
+                    reasons: vec!["conditional"],
+                },
+            ],
+        },
+    ]
+});
+
+pub(crate) static IT_NOUN_TESTS: LazyLock<[TransformTest; 5]> = LazyLock::new(|| {
+    [
+        TransformTest {
+            term: "gatto",
+            sources: vec![LanguageTransformerTestCase {
+                inner: "gatti",
+                rule: "ns",
+                reasons: vec!["plural"],
+            }],
+        },
+        TransformTest {
+            term: "casa",
+            sources: vec![LanguageTransformerTestCase {
+                inner: "case",
+                rule: "ns",
+                reasons: vec!["plural"],
+            }],
+        },
+        TransformTest {
+            term: "fiore",
+            sources: vec![LanguageTransformerTestCase {
+                inner: "fiori",
+                rule: "ns",
+                reasons: vec!["plural"],
+            }],
+        },
+        TransformTest {
+            term: "lago",
+            sources: vec![LanguageTransformerTestCase {
+                inner: "laghi",
+                rule: "ns",
+                reasons: vec!["plural"],
+            }],
+        },
+        TransformTest {
+            term: "uomo",
+            sources: vec![LanguageTransformerTestCase {
+                inner: "uomini",
+                rule: "ns",
+                reasons: vec!["plural"],
+            }],
+        },
+    ]
+});
+
+pub(crate) static IT_ADJECTIVE_TESTS: LazyLock<[TransformTest; 2]> = LazyLock::new(|| {
+    [
+        TransformTest {
+            term: "rosso",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "rossa",
+                    rule: "adj",
+                    reasons: vec!["feminine adjective"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "rossi",
+                    rule: "adj",
+                    reasons: vec!["plural"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "rosse",
+                    rule: "adj",
+                    reasons: vec!["feminine adjective", "plural"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "bianco",
+            sources: vec![LanguageTransformerTestCase {
+                inner: "bianche",
+                rule: "adj",
+                reasons: vec!["feminine adjective", "plural"],
+            }],
+        },
+    ]
+});
+
+pub(crate) static IT_ENCLITIC_TESTS: LazyLock<[TransformTest; 5]> = LazyLock::new(|| {
+    [
+        TransformTest {
+            term: "dire",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "dimmelo",
+                    rule: "v",
+                    reasons: vec!["imperative", "enclitic pronoun"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "dirmelo",
+                    rule: "v",
+                    reasons: vec!["enclitic pronoun"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "parlare",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "parlargli",
+                    rule: "v",
+                    reasons: vec!["enclitic pronoun"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "parlandogli",
+                    rule: "v",
+                    reasons: vec!["gerund", "enclitic pronoun"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "parlami",
+                    rule: "v",
+                    reasons: vec!["imperative", "enclitic pronoun"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "vedere",
+            sources: vec![LanguageTransformerTestCase {
+                inner: "vederlo",
+                rule: "v",
+                reasons: vec!["enclitic pronoun"],
+            }],
+        },
+        TransformTest {
+            term: "fare",
+            sources: vec![LanguageTransformerTestCase {
+                inner: "fammi",
+                rule: "v",
+                reasons: vec!["imperative", "enclitic pronoun"],
+            }],
+        },
+        TransformTest {
+            term: "porre",
+            sources: vec![LanguageTransformerTestCase {
+                inner: "porglielo",
+                rule: "v",
+                reasons: vec!["enclitic pronoun"],
+            }],
+        },
+    ]
+});
+
+#[cfg(test)]
+mod ittransforms {
+    use crate::{
+        it::it_transforms::{ITALIAN_TRANSFORMS_DESCRIPTOR, IT_TRANSFORM_TESTS},
+        ja::ja_transforms::has_term_reasons,
+        transformer::LanguageTransformer,
+    };
+
+    #[test]
+    fn transforms() {
+        let mut lt = LanguageTransformer::new();
+        lt.add_descriptor(&ITALIAN_TRANSFORMS_DESCRIPTOR).unwrap();
+
+        for test_vec in IT_TRANSFORM_TESTS.into_iter() {
+            for test in test_vec {
+                let term = test.term;
+                for case in &test.sources {
+                    let source = case.inner;
+                    let rule = case.rule;
+                    let expected_reasons = &case.reasons;
+
+                    let result =
+                        has_term_reasons(&lt, source, term, Some(rule), Some(expected_reasons));
+                    if let Err(e) = result {
+                        panic!("Failed: {} -> {e}", case.inner);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod it_transforms;
@@ -1,6 +1,7 @@
 use crate::{
     transformer::{
-        Condition, ConditionMap, LanguageTransformer, Rule, RuleI18n, RuleType, SuffixRule,
+        Condition, ConditionFlags, ConditionMap, LanguageTransformer, Rule, RuleI18n, RuleType,
+        SuffixRule,
     },
     transforms::inflection,
 };
@@ -38,7 +39,7 @@ pub(crate) struct LanguageTransformerTestCase {
 #[derive(Debug)]
 pub(crate) struct HasTermReasons {
     pub(crate) reasons: Vec<String>,
-    pub(crate) rules: usize,
+    pub(crate) rules: ConditionFlags,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -1619,6 +1620,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("れば", "る", &["-ば"], &["v1", "v5", "vk", "vs", "vz"], RuleType::Suffix),
                     inflection("れば", "",   &["-ば"], &["-ます"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -1644,6 +1646,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("みゃ", "めば", &["-ゃ"], &["-ば"], RuleType::Suffix),
                     inflection("りゃ", "れば", &["-ゃ"], &["-ば"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -1677,6 +1680,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来ちゃ", "来る", &["v5"], &["vk"], RuleType::Suffix),
                     inflection("來ちゃ", "來る", &["v5"], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -1710,6 +1714,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来ちゃう", "来る", &["v5"], &["vk"], RuleType::Suffix),
                     inflection("來ちゃう", "來る", &["v5"], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -1743,6 +1748,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来ちまう", "来る", &["v5"], &["vk"], RuleType::Suffix),
                     inflection("來ちまう", "來る", &["v5"], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -1763,6 +1769,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("てしまう", "て", &["v5"], &["-て"], RuleType::Suffix),
                     inflection("でしまう", "で", &["v5"], &["-て"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -1795,6 +1802,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来なさい", "来る", &["-なさい"], &["vk"], RuleType::Suffix),
                     inflection("來なさい", "來る", &["-なさい"], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -1828,6 +1836,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来そう", "来る", &[], &["vk"], RuleType::Suffix),
                     inflection("來そう", "來る", &[], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -1861,6 +1870,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来すぎる", "来る", &["v1"], &["vk"], RuleType::Suffix),
                     inflection("來すぎる", "來る", &["v1"], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -1894,6 +1904,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来過ぎる", "来る", &["v1"], &["vk"], RuleType::Suffix),
                     inflection("來過ぎる", "來る", &["v1"], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -1926,6 +1937,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来たい", "来る", &["adj-i"], &["vk"], RuleType::Suffix),
                     inflection("來たい", "來る", &["adj-i"], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -1963,6 +1975,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     &[],
                     &["v5"]
                 ).into_iter().map(Into::into)).chain(std::iter::once(inflection("ましたら", "ます", &[], &["-ます"], RuleType::Suffix))).collect(),
+                weight: None,
             },
         ),
         (
@@ -1996,6 +2009,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来たり", "来る", &[], &["vk"], RuleType::Suffix),
                     inflection("來たり", "來る", &[], &["vk"], RuleType::Suffix),
                 ].into_iter().chain(irregular_verb_inflections(IrregularVerbSuffix::たり, &[], &["v5"]).into_iter().map(Into::into)).collect(),
+                weight: None,
             },
         ),
         (
@@ -2036,6 +2050,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     ).into_iter().map(Into::into))
                     .chain(Vec::from_iter([inflection("まして", "ます", &[], &["-ます"], RuleType::Suffix)]))
                     .collect(),
+                weight: None,
             },
         ),
         (
@@ -2068,6 +2083,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来ず", "来る", &[], &["vk"], RuleType::Suffix),
                     inflection("來ず", "來る", &[], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2100,6 +2116,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来ぬ", "来る", &[], &["vk"], RuleType::Suffix),
                     inflection("來ぬ", "來る", &[], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2132,6 +2149,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来ん", "来る", &["-ん"], &["vk"], RuleType::Suffix),
                     inflection("來ん", "來る", &["-ん"], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2164,6 +2182,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来んばかり", "来る", &[], &["vk"], RuleType::Suffix),
                     inflection("來んばかり", "來る", &[], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2196,6 +2215,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来んとする", "来る", &["vs"], &["vk"], RuleType::Suffix),
                     inflection("來んとする", "來る", &["vs"], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2228,6 +2248,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来む", "来る", &[], &["vk"], RuleType::Suffix),
                     inflection("來む", "來る", &[], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2260,6 +2281,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来ざる", "来る", &[], &["vk"], RuleType::Suffix),
                     inflection("來ざる", "來る", &[], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2292,6 +2314,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来ねば", "来る", &["-ば"], &["vk"], RuleType::Suffix),
                     inflection("來ねば", "來る", &["-ば"], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2309,6 +2332,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                 rules: vec![
                     inflection("く", "い", &["-く"], &["adj-i"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2344,6 +2368,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来させる", "来る", &["v1"], &["vk"], RuleType::Suffix),
                     inflection("來させる", "來る", &["v1"], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2377,6 +2402,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来さす", "来る", &["v5ss"], &["vk"], RuleType::Suffix),
                     inflection("來さす", "來る", &["v5ss"], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2413,6 +2439,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来い", "来る", &[], &["vk"], RuleType::Suffix),
                     inflection("來い", "來る", &[], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2464,6 +2491,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来", "来る", &[], &["vk"], RuleType::Suffix),
                     inflection("來", "來る", &[], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2498,6 +2526,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("來ない", "來る", &["adj-i"], &["vk"], RuleType::Suffix),
                     inflection("ません", "ます", &["-ません"], &["-ます"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2515,6 +2544,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                 rules: vec![
                     inflection("さ", "い", &[], &["adj-i"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2547,6 +2577,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来られる", "来る", &["v1"], &["vk"], RuleType::Suffix),
                     inflection("來られる", "來る", &["v1"], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2587,6 +2618,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("でした", "", &["-た"], &["-ません"], RuleType::Suffix),
                     inflection("かった", "", &["-た"], &["-ません", "-ん"], RuleType::Suffix)
                 ]).collect(),
+                weight: None,
             },
         ),
         (
@@ -2620,6 +2652,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("來ます", "來る", &["-ます"], &["vk"], RuleType::Suffix),
                     inflection("くあります", "い", &["-ます"], &["adj-i"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2650,6 +2683,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来れる", "来る", &["v1"], &["vk"], RuleType::Suffix),
                     inflection("來れる", "來る", &["v1"], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2674,6 +2708,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("来られる", "来る", &["v1"], &["vk"], RuleType::Suffix),
                     inflection("來られる", "來る", &["v1"], &["vk"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2708,6 +2743,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("ましょう", "ます", &[], &["-ます"], RuleType::Suffix),
                     inflection("かろう", "い", &[], &["adj-i"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2741,6 +2777,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("來よっか", "來る", &[], &["vk"], RuleType::Suffix),
                     inflection("ましょっか", "ます", &[], &["-ます"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2768,6 +2805,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("來まい", "來る", &[], &["vk"], RuleType::Suffix),
                     inflection("まい", "", &[], &["-ます"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2790,6 +2828,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("ないでおく", "ない", &["v5"], &["adj-i"], RuleType::Suffix),
                     inflection("ないどく", "ない", &["v5"], &["adj-i"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2814,6 +2853,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("とる", "て", &["v5"], &["-て"], RuleType::Suffix),
                     inflection("ないでいる", "ない", &["v1"], &["adj-i"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2831,6 +2871,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                 rules: vec![
                     inflection("き", "い", &[], &["adj-i"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2849,6 +2890,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("げ", "い", &[], &["adj-i"], RuleType::Suffix),
                     inflection("気", "い", &[], &["adj-i"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2866,6 +2908,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                 rules: vec![
                     inflection("がる", "い", &["v5"], &["adj-i"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2924,6 +2967,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("べぇ", "ばい", &[], &["adj-i"], RuleType::Suffix),
                     inflection("てぇ", "たい", &[], &["adj-i"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2945,6 +2989,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("んなきゃ", "らなきゃ", &[], &["-ゃ"], RuleType::Suffix),
                     inflection("んなきゃ", "れなきゃ", &[], &["-ゃ"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2960,6 +3005,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                 rules: vec![
                     inflection("んな", "る", &[], &["v"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -2982,6 +3028,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("ひんかった", "なかった", &["-た"], &["-た"], RuleType::Suffix),
                     inflection("うてへん", "ってない", &[], &["adj-i"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -3013,6 +3060,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("ようて", "やって", &["-て"], &["-て"], RuleType::Suffix),
                     inflection("ゆうて", "いって", &["-て"], &["-て"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -3044,6 +3092,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("ようた", "やった", &["-た"], &["-た"], RuleType::Suffix),
                     inflection("ゆうた", "いった", &["-た"], &["-た"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -3075,6 +3124,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("ようたら", "やったら", &[], &[], RuleType::Suffix),
                     inflection("ゆうたら", "いったら", &[], &[], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -3106,6 +3156,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("ようたり", "やったり", &[], &[], RuleType::Suffix),
                     inflection("ゆうたり", "いったり", &[], &[], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -3131,6 +3182,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("よう", "よく", &[], &["-く"], RuleType::Suffix),
                     inflection("しゅう", "しく", &[], &["-く"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -3156,6 +3208,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("ようて", "よくて", &["-て"], &["-て"], RuleType::Suffix),
                     inflection("しゅうて", "しくて", &["-て"], &["-て"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
         (
@@ -3181,6 +3234,7 @@ pub static JP_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
                     inflection("ようない", "よくない", &["adj-i"], &["adj-i"], RuleType::Suffix),
                     inflection("しゅうない", "しくない", &["adj-i"], &["adj-i"], RuleType::Suffix),
                 ],
+                weight: None,
             },
         ),
     ]))
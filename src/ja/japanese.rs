@@ -485,25 +485,20 @@ fn get_furigana_kana_segments(text: &str, reading: &str) -> Vec<FuriganaSegment>
 
     let mut state = text.chars().next() == reading.chars().next();
 
-    loop {
-        match (text_iter.next(), reading_iter.next()) {
-            (Some((i_t, tc)), Some((i_r, rc))) => {
-                let new_state = tc == rc;
-                if state != new_state {
-                    new_segments.push(FuriganaSegment::create_furigana_segment(
-                        text[start_idx..i_t].to_string(),
-                        if state {
-                            None
-                        } else {
-                            Some(reading[reading_start_idx..i_r].to_string())
-                        },
-                    ));
-                    state = new_state;
-                    start_idx = i_t;
-                    reading_start_idx = i_r;
-                }
-            }
-            _ => break,
+    while let (Some((i_t, tc)), Some((i_r, rc))) = (text_iter.next(), reading_iter.next()) {
+        let new_state = tc == rc;
+        if state != new_state {
+            new_segments.push(FuriganaSegment::create_furigana_segment(
+                text[start_idx..i_t].to_string(),
+                if state {
+                    None
+                } else {
+                    Some(reading[reading_start_idx..i_r].to_string())
+                },
+            ));
+            state = new_state;
+            start_idx = i_t;
+            reading_start_idx = i_r;
         }
     }
 
@@ -0,0 +1,370 @@
+use indexmap::IndexMap;
+use std::sync::LazyLock;
+
+use crate::{
+    ja::ja_transforms::{LanguageTransformerTestCase, TransformTest},
+    transformer::{
+        Condition, ConditionMap, LanguageTransformDescriptor, Rule, RuleType, Transform,
+        TransformMap,
+    },
+    transforms::inflection,
+};
+
+// Like Yomitan, every rule is written on disassembled jamo (see `ko::korean::disassemble_hangul`),
+// which lets a single rule match every syllable an ending can merge into: `ㅏㅇㅛ` covers 가요, 자요...
+
+/// What is left once an ending is removed: the `-다` form of a stem that may still be
+/// past tense or honorific.
+const AFTER_ENDING: &[&str] = &["v", "adj", "past", "hon"];
+
+/// `(inflected, deinflected)` pairs.
+type Endings = &'static [(&'static str, &'static str)];
+
+fn suffix_rules(
+    endings: Endings,
+    conditions_in: &'static [&'static str],
+    conditions_out: &'static [&'static str],
+) -> Vec<Rule> {
+    endings
+        .iter()
+        .map(|&(inflected, deinflected)| {
+            inflection(
+                inflected,
+                deinflected,
+                conditions_in,
+                conditions_out,
+                RuleType::Suffix,
+            )
+        })
+        .collect()
+}
+
+pub static KOREAN_TRANSFORMS_DESCRIPTOR: LazyLock<LanguageTransformDescriptor> =
+    LazyLock::new(|| LanguageTransformDescriptor {
+        language: "ko",
+        conditions: &KO_CONDITIONS_MAP,
+        transforms: &KO_TRANSFORMS_MAP,
+    });
+
+pub static KO_CONDITIONS_MAP: LazyLock<ConditionMap> = LazyLock::new(|| {
+    ConditionMap(IndexMap::from([
+        (
+            "v",
+            Condition {
+                name: "Verb",
+                is_dictionary_form: true,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "adj",
+            Condition {
+                name: "Adjective",
+                is_dictionary_form: true,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "past",
+            Condition {
+                name: "Past tense",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "hon",
+            Condition {
+                name: "Honorific",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "ending",
+            Condition {
+                name: "Conjugated form",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+    ]))
+});
+
+pub static KO_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
+    TransformMap(IndexMap::from([
+        (
+            "-아요/-어요",
+            Transform {
+                name: "-아요/-어요",
+                description: Some("Polite informal ending"),
+                rules: [
+                    suffix_rules(
+                        &[
+                            ("ㅇㅏㅇㅛ", "ㄷㅏ"),
+                            ("ㅇㅓㅇㅛ", "ㄷㅏ"),
+                            // the ending merges into a stem ending in a vowel
+                            ("ㅏㅇㅛ", "ㅏㄷㅏ"),
+                            ("ㅓㅇㅛ", "ㅓㄷㅏ"),
+                            ("ㅐㅇㅛ", "ㅐㄷㅏ"),
+                            ("ㅕㅇㅛ", "ㅣㄷㅏ"),
+                            ("ㅗㅏㅇㅛ", "ㅗㄷㅏ"),
+                            ("ㅜㅓㅇㅛ", "ㅜㄷㅏ"),
+                            ("ㅗㅐㅇㅛ", "ㅗㅣㄷㅏ"),
+                            ("ㅎㅐㅇㅛ", "ㅎㅏㄷㅏ"),
+                        ],
+                        &["ending"],
+                        AFTER_ENDING,
+                    ),
+                    // -(으)시- + -어요
+                    suffix_rules(&[("ㅅㅔㅇㅛ", "ㅅㅣㄷㅏ")], &["ending"], &["hon"]),
+                ]
+                .concat(),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "-습니다",
+            Transform {
+                name: "-습니다",
+                description: Some("Polite formal ending"),
+                rules: suffix_rules(
+                    &[("ㅅㅡㅂㄴㅣㄷㅏ", "ㄷㅏ"), ("ㅂㄴㅣㄷㅏ", "ㄷㅏ")],
+                    &["ending"],
+                    AFTER_ENDING,
+                ),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "-고",
+            Transform {
+                name: "-고",
+                description: Some("Connective ending: and"),
+                rules: suffix_rules(&[("ㄱㅗ", "ㄷㅏ")], &["ending"], AFTER_ENDING),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "-(으)면",
+            Transform {
+                name: "-(으)면",
+                description: Some("Conditional ending: if, when"),
+                rules: suffix_rules(
+                    &[("ㅇㅡㅁㅕㄴ", "ㄷㅏ"), ("ㅁㅕㄴ", "ㄷㅏ")],
+                    &["ending"],
+                    AFTER_ENDING,
+                ),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "-았/었-",
+            Transform {
+                name: "-았/었-",
+                description: Some("Past tense"),
+                rules: suffix_rules(
+                    &[
+                        ("ㅇㅏㅆㄷㅏ", "ㄷㅏ"),
+                        ("ㅇㅓㅆㄷㅏ", "ㄷㅏ"),
+                        ("ㅏㅆㄷㅏ", "ㅏㄷㅏ"),
+                        ("ㅓㅆㄷㅏ", "ㅓㄷㅏ"),
+                        ("ㅐㅆㄷㅏ", "ㅐㄷㅏ"),
+                        ("ㅕㅆㄷㅏ", "ㅣㄷㅏ"),
+                        ("ㅗㅏㅆㄷㅏ", "ㅗㄷㅏ"),
+                        ("ㅜㅓㅆㄷㅏ", "ㅜㄷㅏ"),
+                        ("ㅗㅐㅆㄷㅏ", "ㅗㅣㄷㅏ"),
+                        ("ㅎㅐㅆㄷㅏ", "ㅎㅏㄷㅏ"),
+                    ],
+                    &["past"],
+                    &["v", "adj", "hon"],
+                ),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "-(으)시-",
+            Transform {
+                name: "-(으)시-",
+                description: Some("Honorific"),
+                rules: suffix_rules(
+                    &[("ㅇㅡㅅㅣㄷㅏ", "ㄷㅏ"), ("ㅅㅣㄷㅏ", "ㄷㅏ")],
+                    &["hon"],
+                    &["v", "adj"],
+                ),
+                i18n: None,
+                weight: None,
+            },
+        ),
+    ]))
+});
+
+pub(crate) static KO_TRANSFORM_TESTS: LazyLock<[&[TransformTest]; 1]> =
+    LazyLock::new(|| [&*KO_VERB_TESTS]);
+
+/// Written in syllable blocks, the test disassembles them like the `disassemble_hangul` processor.
+pub(crate) static KO_VERB_TESTS: LazyLock<[TransformTest; 6]> = LazyLock::new(|| {
+    [
+        TransformTest {
+            term: "먹다",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "먹어요",
+                    rule: "v",
+                    reasons: vec!["-아요/-어요"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "먹었다",
+                    rule: "v",
+                    reasons: vec!["-았/었-"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "먹었어요",
+                    rule: "v",
+                    reasons: vec!["-았/었-", "-아요/-어요"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "먹고",
+                    rule: "v",
+                    reasons: vec!["-고"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "먹으면",
+                    rule: "v",
+                    reasons: vec!["-(으)면"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "먹습니다",
+                    rule: "v",
+                    reasons: vec!["-습니다"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "가다",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "가요",
+                    rule: "v",
+                    reasons: vec!["-아요/-어요"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "갔어요",
+                    rule: "v",
+                    reasons: vec!["-았/었-", "-아요/-어요"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "가면",
+                    rule: "v",
+                    reasons: vec!["-(으)면"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "가세요",
+                    rule: "v",
+                    reasons: vec!["-(으)시-", "-아요/-어요"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "가셨어요",
+                    rule: "v",
+                    reasons: vec!["-(으)시-", "-았/었-", "-아요/-어요"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "갑니다",
+                    rule: "v",
+                    reasons: vec!["-습니다"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "하다",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "해요",
+                    rule: "v",
+                    reasons: vec!["-아요/-어요"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "했어요",
+                    rule: "v",
+                    reasons: vec!["-았/었-", "-아요/-어요"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "보다",
+            sources: vec![LanguageTransformerTestCase {
+                inner: "봤어요",
+                rule: "v",
+                reasons: vec!["-았/었-", "-아요/-어요"],
+            }],
+        },
+        TransformTest {
+            term: "읽다",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "읽으셨어요",
+                    rule: "v",
+                    reasons: vec!["-(으)시-", "-았/었-", "-아요/-어요"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "읽으면",
+                    rule: "v",
+                    reasons: vec!["-(으)면"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "좋다",
+            sources: vec![LanguageTransformerTestCase {
+                inner: "좋아요",
+                rule: "adj",
+                reasons: vec!["-아요/-어요"],
+            }],
+        },
+    ]
+});
+
+#[cfg(test)]
+mod kotransforms {
+    use crate::{
+        ja::ja_transforms::has_term_reasons,
+        ko::{
+            ko_transforms::{KOREAN_TRANSFORMS_DESCRIPTOR, KO_TRANSFORM_TESTS},
+            korean::disassemble_hangul,
+        },
+        transformer::LanguageTransformer,
+    };
+
+    #[test]
+    fn transforms() {
+        let mut lt = LanguageTransformer::new();
+        lt.add_descriptor(&KOREAN_TRANSFORMS_DESCRIPTOR).unwrap();
+
+        for test_vec in KO_TRANSFORM_TESTS.into_iter() {
+            for test in test_vec {
+                let term = disassemble_hangul(test.term);
+                for case in &test.sources {
+                    let source = disassemble_hangul(case.inner);
+                    let rule = case.rule;
+                    let expected_reasons = &case.reasons;
+
+                    let result =
+                        has_term_reasons(&lt, &source, &term, Some(rule), Some(expected_reasons));
+                    if let Err(e) = result {
+                        panic!("Failed: {} -> {e}", case.inner);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,260 @@
+use crate::cjk_utils::{is_code_point_in_ranges, CodepointRange};
+
+pub const HANGUL_SYLLABLES_RANGE: CodepointRange = (0xac00, 0xd7a3);
+pub const HANGUL_JAMO_RANGE: CodepointRange = (0x1100, 0x11ff);
+pub const HANGUL_COMPATIBILITY_JAMO_RANGE: CodepointRange = (0x3130, 0x318f);
+pub const HANGUL_JAMO_EXTENDED_A_RANGE: CodepointRange = (0xa960, 0xa97f);
+pub const HANGUL_JAMO_EXTENDED_B_RANGE: CodepointRange = (0xd7b0, 0xd7ff);
+pub const HALFWIDTH_HANGUL_RANGE: CodepointRange = (0xffa0, 0xffdc);
+
+/// Hangul ranges, roughly ordered in order of expected frequency.
+pub const KOREAN_RANGES: [CodepointRange; 6] = [
+    HANGUL_SYLLABLES_RANGE,
+    HANGUL_COMPATIBILITY_JAMO_RANGE,
+    HANGUL_JAMO_RANGE,
+    HANGUL_JAMO_EXTENDED_A_RANGE,
+    HANGUL_JAMO_EXTENDED_B_RANGE,
+    HALFWIDTH_HANGUL_RANGE,
+];
+
+const SYLLABLE_BASE: u32 = 0xac00;
+const MEDIAL_COUNT: u32 = 21;
+const FINAL_COUNT: u32 = 28;
+
+/// Initial consonants in syllable block order.
+const INITIALS: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ',
+    'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+/// Vowels in syllable block order.
+const MEDIALS: [char; 21] = [
+    'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ', 'ㅜ', 'ㅝ', 'ㅞ',
+    'ㅟ', 'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ',
+];
+
+/// Final consonants in syllable block order, index `0` being no final.
+const FINALS: [char; 28] = [
+    '\0', 'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ',
+    'ㅁ', 'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+/// Vowels written as two jamo once disassembled.
+const COMPOUND_VOWELS: [(char, [char; 2]); 7] = [
+    ('ㅘ', ['ㅗ', 'ㅏ']),
+    ('ㅙ', ['ㅗ', 'ㅐ']),
+    ('ㅚ', ['ㅗ', 'ㅣ']),
+    ('ㅝ', ['ㅜ', 'ㅓ']),
+    ('ㅞ', ['ㅜ', 'ㅔ']),
+    ('ㅟ', ['ㅜ', 'ㅣ']),
+    ('ㅢ', ['ㅡ', 'ㅣ']),
+];
+
+/// Final consonant clusters written as two jamo once disassembled.
+/// Double consonants (ㄲ, ㅆ...) are kept as a single jamo.
+const COMPOUND_FINALS: [(char, [char; 2]); 11] = [
+    ('ㄳ', ['ㄱ', 'ㅅ']),
+    ('ㄵ', ['ㄴ', 'ㅈ']),
+    ('ㄶ', ['ㄴ', 'ㅎ']),
+    ('ㄺ', ['ㄹ', 'ㄱ']),
+    ('ㄻ', ['ㄹ', 'ㅁ']),
+    ('ㄼ', ['ㄹ', 'ㅂ']),
+    ('ㄽ', ['ㄹ', 'ㅅ']),
+    ('ㄾ', ['ㄹ', 'ㅌ']),
+    ('ㄿ', ['ㄹ', 'ㅍ']),
+    ('ㅀ', ['ㄹ', 'ㅎ']),
+    ('ㅄ', ['ㅂ', 'ㅅ']),
+];
+
+/// Checks if a string contains at least one Hangul syllable or jamo.
+pub fn is_string_partially_korean(s: &str) -> bool {
+    s.chars().any(|c| is_code_point_korean(c as u32))
+}
+
+/// Checks if a given Unicode code point is within [`KOREAN_RANGES`].
+pub fn is_code_point_korean(code_point: u32) -> bool {
+    is_code_point_in_ranges(code_point, &KOREAN_RANGES)
+}
+
+fn is_jamo_vowel(c: char) -> bool {
+    ('ㅏ'..='ㅣ').contains(&c)
+}
+
+fn is_jamo_consonant(c: char) -> bool {
+    ('ㄱ'..='ㅎ').contains(&c)
+}
+
+fn split_compound(c: char, compounds: &[(char, [char; 2])]) -> Option<[char; 2]> {
+    compounds
+        .iter()
+        .find(|(compound, _)| *compound == c)
+        .map(|(_, parts)| *parts)
+}
+
+fn join_compound(first: char, second: char, compounds: &[(char, [char; 2])]) -> Option<char> {
+    compounds
+        .iter()
+        .find(|(_, parts)| *parts == [first, second])
+        .map(|(compound, _)| *compound)
+}
+
+fn push_jamo(result: &mut String, jamo: char) {
+    match split_compound(jamo, &COMPOUND_VOWELS).or_else(|| split_compound(jamo, &COMPOUND_FINALS))
+    {
+        Some(parts) => result.extend(parts),
+        None => result.push(jamo),
+    }
+}
+
+/// Splits Hangul syllable blocks into compatibility jamo,
+/// with compound vowels & final clusters split into their parts.
+///
+/// `읽었어요` -> `ㅇㅣㄹㄱㅇㅓㅆㅇㅓㅇㅛ`
+pub fn disassemble_hangul(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() * 3);
+    for c in s.chars() {
+        let code_point = c as u32;
+        if !is_code_point_in_ranges(code_point, &[HANGUL_SYLLABLES_RANGE]) {
+            push_jamo(&mut result, c);
+            continue;
+        }
+        let index = code_point - SYLLABLE_BASE;
+        let initial = index / (MEDIAL_COUNT * FINAL_COUNT);
+        let medial = (index % (MEDIAL_COUNT * FINAL_COUNT)) / FINAL_COUNT;
+        let final_ = index % FINAL_COUNT;
+        result.push(INITIALS[initial as usize]);
+        push_jamo(&mut result, MEDIALS[medial as usize]);
+        if final_ != 0 {
+            push_jamo(&mut result, FINALS[final_ as usize]);
+        }
+    }
+    result
+}
+
+fn compose_syllable(initial: char, medial: char, final_: Option<char>) -> Option<char> {
+    let initial = INITIALS.iter().position(|&c| c == initial)? as u32;
+    let medial = MEDIALS.iter().position(|&c| c == medial)? as u32;
+    let final_ = match final_ {
+        Some(final_) => FINALS.iter().position(|&c| c == final_)? as u32,
+        None => 0,
+    };
+    char::from_u32(SYLLABLE_BASE + (initial * MEDIAL_COUNT + medial) * FINAL_COUNT + final_)
+}
+
+/// Reassembles compatibility jamo into Hangul syllable blocks,
+/// the inverse of [`disassemble_hangul`].
+///
+/// A consonant followed by a vowel always starts a new syllable,
+/// so `ㅇㅣㄹㄱㅇㅓ` -> `읽어` but `ㄷㅏㄹㄱㅏ` -> `달가`.
+/// Jamo that can't form a syllable are left as is.
+pub fn reassemble_hangul(s: &str) -> String {
+    let chars: Vec<char> = s.chars().collect();
+    let starts_syllable = |i: usize| {
+        i + 1 < chars.len() && INITIALS.contains(&chars[i]) && is_jamo_vowel(chars[i + 1])
+    };
+    let mut result = String::with_capacity(s.len());
+    let mut i = 0;
+    while i < chars.len() {
+        if !starts_syllable(i) {
+            result.push(chars[i]);
+            i += 1;
+            continue;
+        }
+        let initial = chars[i];
+        let mut medial = chars[i + 1];
+        let mut j = i + 2;
+        if let Some(compound) = chars
+            .get(j)
+            .and_then(|&next| join_compound(medial, next, &COMPOUND_VOWELS))
+        {
+            medial = compound;
+            j += 1;
+        }
+        let mut final_ = None;
+        if j < chars.len()
+            && is_jamo_consonant(chars[j])
+            && FINALS.contains(&chars[j])
+            && !starts_syllable(j)
+        {
+            let mut consonant = chars[j];
+            j += 1;
+            if let Some(compound) = chars
+                .get(j)
+                .and_then(|&next| join_compound(consonant, next, &COMPOUND_FINALS))
+                .filter(|_| !starts_syllable(j))
+            {
+                consonant = compound;
+                j += 1;
+            }
+            final_ = Some(consonant);
+        }
+        match compose_syllable(initial, medial, final_) {
+            Some(syllable) => result.push(syllable),
+            None => result.extend(&chars[i..j]),
+        }
+        i = j;
+    }
+    result
+}
+
+#[cfg(test)]
+mod ko_tests {
+    use super::*;
+
+    #[test]
+    fn partially_korean() {
+        assert!(is_string_partially_korean("한국어"));
+        assert!(is_string_partially_korean("ㅎㅏㄴ"));
+        assert!(!is_string_partially_korean("hangugeo"));
+        assert!(!is_string_partially_korean("漢字"));
+    }
+
+    #[test]
+    fn disassemble() {
+        assert_eq!(disassemble_hangul("가"), "ㄱㅏ");
+        assert_eq!(disassemble_hangul("읽었어요"), "ㅇㅣㄹㄱㅇㅓㅆㅇㅓㅇㅛ");
+        assert_eq!(disassemble_hangul("봐요"), "ㅂㅗㅏㅇㅛ");
+        assert_eq!(disassemble_hangul("값"), "ㄱㅏㅂㅅ");
+        assert_eq!(disassemble_hangul("한국어 text"), "ㅎㅏㄴㄱㅜㄱㅇㅓ text");
+    }
+
+    #[test]
+    fn reassemble() {
+        for text in [
+            "가",
+            "읽었어요",
+            "봐요",
+            "값",
+            "읽어",
+            "달가",
+            "됐습니다",
+            "괜찮아요",
+            "한국어 text",
+        ] {
+            assert_eq!(reassemble_hangul(&disassemble_hangul(text)), text);
+        }
+        // lone jamo
+        assert_eq!(reassemble_hangul("ㅋㅋ"), "ㅋㅋ");
+        assert_eq!(reassemble_hangul("ㅏ"), "ㅏ");
+    }
+
+    #[test]
+    fn language_descriptor() {
+        use crate::languages::{get_all_language_text_processors, is_text_lookup_worthy};
+
+        assert!(is_text_lookup_worthy("먹어요", "ko"));
+        assert!(!is_text_lookup_worthy("meogeoyo", "ko"));
+        let processors = get_all_language_text_processors()
+            .into_iter()
+            .find(|p| p.iso == "ko")
+            .unwrap();
+        let apply = |processors: &[crate::language_d::TextProcessorWithId], text: &str| {
+            processors.iter().fold(text.to_string(), |text, p| {
+                (p.processor.process)(&text, p.processor.options[1].clone())
+            })
+        };
+        let disassembled = apply(&processors.pre, "먹어요");
+        assert_eq!(disassembled, "ㅁㅓㄱㅇㅓㅇㅛ");
+        assert_eq!(apply(&processors.post, &disassembled), "먹어요");
+    }
+}
@@ -0,0 +1,2 @@
+pub mod ko_transforms;
+pub mod korean;
@@ -111,6 +111,30 @@ pub enum AnyTextProcessor {
     // English Processors
     Decapitalize(TextProcessor),
     CapitalizeFirst(TextProcessor),
+
+    // Chinese Processors
+    ChineseVariants(BidirectionalConversionPreProcessor),
+    PinyinTones(BidirectionalConversionPreProcessor),
+    RemovePinyinTones(TextProcessor),
+
+    // Arabic Processors
+    RemoveArabicDiacritics(TextProcessor),
+    RemoveTatweel(TextProcessor),
+    NormalizeAlefVariants(TextProcessor),
+    AddHamzaTop(TextProcessor),
+    AddHamzaBottom(TextProcessor),
+    ConvertAlefMaksuraToYeh(TextProcessor),
+
+    // Persian Processors
+    PersianCharacters(BidirectionalConversionPreProcessor),
+    RemoveZeroWidthNonJoiner(TextProcessor),
+
+    // Russian Processors
+    RemoveRussianDiacritics(TextProcessor),
+
+    // Korean Processors
+    DisassembleHangul(TextProcessor),
+    ReassembleHangul(TextProcessor),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
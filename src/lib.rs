@@ -1,19 +1,28 @@
 #![allow(dead_code, unused_imports)]
 
+pub mod ar;
+pub mod arabic_utils;
 pub mod cjk_utils;
+pub mod descriptor_file;
 pub mod descriptors;
 pub mod en;
 pub mod es;
+pub mod fa;
+pub mod it;
 pub mod ja;
+pub mod ko;
 pub mod language_d;
 pub mod languages;
 pub mod multi_language_transformer;
+pub mod ru;
+pub mod rule_index;
 pub mod text_preprocessors;
 pub mod text_processors;
 pub mod text_scanner;
 pub mod transformer;
 pub mod transforms;
 pub mod wanakana;
+pub mod yue;
 pub mod zh;
 
 fn main() {}
@@ -2,7 +2,12 @@ use std::collections::HashMap;
 
 use crate::{
     languages::get_all_language_transform_descriptors,
-    transformer::{InflectionRule, InflectionRuleChain, LanguageTransformer, TransformedText},
+    transformer::{
+        CandidateArena, CandidateReport, ConditionFlags, ConditionName, ConjugationTableEntry,
+        Explanation, InflectionRule, InflectionRuleChain, LanguageTransformer,
+        LanguageTransformerError, RankedTransformedText, RankingOptions, TermLookup, TransformIter,
+        TransformOptions, TransformReport, TransformedText,
+    },
 };
 
 // key: language (ie: "en", "ja")
@@ -34,11 +39,18 @@ impl MultiLanguageTransformer {
         }
     }
 
+    /// See [`LanguageTransformer::build_rule_index`].
+    pub fn build_rule_indexes(&mut self) {
+        for lt in self.inner.values_mut() {
+            lt.build_rule_index();
+        }
+    }
+
     pub fn get_condition_flags_from_parts_of_speech(
         &self,
         language: &str,
         parts_of_speech: &[String],
-    ) -> usize {
+    ) -> ConditionFlags {
         self.inner
             .get(language)
             .map(|lt| lt.get_condition_flags_from_parts_of_speech(parts_of_speech))
@@ -49,7 +61,7 @@ impl MultiLanguageTransformer {
         &self,
         language: &str,
         condition_types: &[String],
-    ) -> usize {
+    ) -> ConditionFlags {
         self.inner
             .get(language)
             .map(|lt| lt.get_condition_flags_from_condition_types(condition_types))
@@ -60,13 +72,28 @@ impl MultiLanguageTransformer {
         &self,
         language: &str,
         condition_type: &str,
-    ) -> usize {
+    ) -> ConditionFlags {
         self.inner
             .get(language)
             .map(|lt| lt.get_condition_flags_from_single_condition_type(condition_type))
             .unwrap_or(0)
     }
 
+    /// See [`LanguageTransformer::condition_names_from_flags`].
+    ///
+    /// Empty for an unknown language.
+    pub fn condition_names_from_flags(
+        &self,
+        language: &str,
+        flags: ConditionFlags,
+        display_locale: Option<&str>,
+    ) -> Vec<ConditionName> {
+        self.inner
+            .get(language)
+            .map(|lt| lt.condition_names_from_flags(flags, display_locale))
+            .unwrap_or_default()
+    }
+
     pub fn transform(&self, language: &str, source_text: &str) -> Vec<TransformedText> {
         match self.inner.get(language) {
             Some(lt) => lt.transform(source_text),
@@ -78,13 +105,150 @@ impl MultiLanguageTransformer {
         }
     }
 
+    /// See [`LanguageTransformer::transform_candidates`].
+    pub fn transform_candidates(
+        &self,
+        language: &str,
+        source_text: &str,
+        options: &TransformOptions,
+    ) -> CandidateReport {
+        match self.inner.get(language) {
+            Some(lt) => lt.transform_candidates(source_text, options),
+            None => CandidateReport {
+                candidates: CandidateArena::new(source_text),
+                truncated: false,
+                warnings: Vec::new(),
+            },
+        }
+    }
+
+    /// [`MultiLanguageTransformer::transform`] sorted by [`LanguageTransformer::rank`].
+    pub fn transform_ranked(
+        &self,
+        language: &str,
+        source_text: &str,
+        options: &RankingOptions,
+    ) -> Vec<RankedTransformedText> {
+        match self.inner.get(language) {
+            Some(lt) => lt.rank(lt.transform(source_text), options),
+            None => self
+                .transform(language, source_text)
+                .into_iter()
+                .map(|transformed_text| RankedTransformedText {
+                    transformed_text,
+                    score: 0.0,
+                })
+                .collect(),
+        }
+    }
+
+    /// See [`LanguageTransformer::transform_with_lookup`].
+    ///
+    /// For an unknown language, `source_text` is only paired with its entries as is.
+    pub fn transform_with_lookup<L: TermLookup>(
+        &self,
+        language: &str,
+        source_text: &str,
+        dictionary: &L,
+    ) -> Vec<(TransformedText, L::Entry)> {
+        match self.inner.get(language) {
+            Some(lt) => lt.transform_with_lookup(source_text, dictionary),
+            None => dictionary
+                .lookup(source_text)
+                .into_iter()
+                .map(|entry| {
+                    let candidate = TransformedText::create_transformed_text(
+                        source_text.to_owned(),
+                        0,
+                        Vec::new(),
+                    );
+                    (candidate, entry)
+                })
+                .collect(),
+        }
+    }
+
+    /// See [`LanguageTransformer::transform_iter`].
+    pub fn transform_iter<'a>(&'a self, language: &str, source_text: &str) -> TransformIter<'a> {
+        TransformIter::new(self.inner.get(language), source_text)
+    }
+
+    /// See [`LanguageTransformer::transform_with_options`].
+    pub fn transform_with_options(
+        &self,
+        language: &str,
+        source_text: &str,
+        options: &TransformOptions,
+    ) -> TransformReport {
+        match self.inner.get(language) {
+            Some(lt) => lt.transform_with_options(source_text, options),
+            None => TransformReport {
+                results: vec![TransformedText::create_transformed_text(
+                    source_text.to_owned(),
+                    0,
+                    Vec::new(),
+                )],
+                truncated: false,
+                warnings: Vec::new(),
+            },
+        }
+    }
+
+    /// See [`LanguageTransformer::inflect`].
+    pub fn inflect(
+        &self,
+        language: &str,
+        dictionary_form: &str,
+        condition_type: &str,
+        transform_ids: &[impl AsRef<str>],
+    ) -> Result<Vec<TransformedText>, LanguageTransformerError> {
+        match self.inner.get(language) {
+            Some(lt) => lt.inflect(dictionary_form, condition_type, transform_ids),
+            None => Err(LanguageTransformerError::MissingLanguage {
+                language: language.to_owned(),
+            }),
+        }
+    }
+
+    /// See [`LanguageTransformer::explain`].
+    pub fn explain(
+        &self,
+        language: &str,
+        transformed_text: &TransformedText,
+    ) -> Result<Explanation, LanguageTransformerError> {
+        match self.inner.get(language) {
+            Some(lt) => lt.explain(transformed_text),
+            None => Err(LanguageTransformerError::MissingLanguage {
+                language: language.to_owned(),
+            }),
+        }
+    }
+
+    /// See [`LanguageTransformer::conjugation_table`].
+    pub fn conjugation_table(
+        &self,
+        language: &str,
+        dictionary_form: &str,
+        part_of_speech: &str,
+        chains: &[&[&str]],
+    ) -> Result<Vec<ConjugationTableEntry>, LanguageTransformerError> {
+        match self.inner.get(language) {
+            Some(lt) => lt.conjugation_table(dictionary_form, part_of_speech, chains),
+            None => Err(LanguageTransformerError::MissingLanguage {
+                language: language.to_owned(),
+            }),
+        }
+    }
+
+    /// See [`LanguageTransformer::get_user_facing_inflection_rules`].
     pub fn get_user_facing_inflection_rules(
         &self,
         language: &str,
         inflection_rules: &[String],
+        display_locale: Option<&str>,
     ) -> InflectionRuleChain {
         match self.inner.get(language) {
-            Some(lt) => lt.get_user_facing_inflection_rules(inflection_rules),
+            Some(lt) => lt.get_user_facing_inflection_rules(inflection_rules, display_locale),
             None => inflection_rules
                 .iter()
                 .map(|rule| InflectionRule {
@@ -98,7 +262,11 @@ impl MultiLanguageTransformer {
 
 #[cfg(test)]
 mod mlt {
-    use crate::transformer::{Trace, TraceFrame, TransformedText};
+    use std::collections::{HashMap, HashSet};
+
+    use crate::transformer::{
+        RankingOptions, Trace, TraceFrame, TransformOptions, TransformedText,
+    };
 
     use super::MultiLanguageTransformer;
     use pretty_assertions::assert_eq as passert_eq;
@@ -113,6 +281,178 @@ mod mlt {
         dbg!(res);
     }
 
+    #[test]
+    fn inflect_es() {
+        let mlt = MultiLanguageTransformer::default();
+        let res = mlt
+            .inflect("es", "hablar", "v_ar", &["participle"])
+            .unwrap();
+        assert!(res.iter().any(|tt| tt.text == "hablado"));
+    }
+
+    #[test]
+    fn conjugation_table_en() {
+        let mlt = MultiLanguageTransformer::default();
+        let table = mlt.conjugation_table("en", "walk", "v", &[]).unwrap();
+        let walked = table.iter().find(|entry| entry.text == "walked").unwrap();
+        assert_eq!(walked.inflection_rules[0].name, "past");
+        assert!(table.iter().any(|entry| entry.text == "walking"));
+    }
+
+    #[test]
+    fn conjugation_table_es() {
+        let mlt = MultiLanguageTransformer::default();
+        let table = mlt.conjugation_table("es", "hablar", "v", &[]).unwrap();
+        let labels = |text: &str| -> Vec<String> {
+            table
+                .iter()
+                .filter(|entry| entry.text == text)
+                .map(|entry| entry.inflection_rules[0].name.clone())
+                .collect()
+        };
+        assert_eq!(
+            labels("hablamos"),
+            ["present indicative", "preterite", "imperative"]
+        );
+        assert_eq!(labels("hablemos"), ["imperative", "present subjunctive"]);
+        assert_eq!(labels("habla"), ["present indicative", "imperative"]);
+        // rows are unique per text & chain
+        let rows: HashSet<(&str, &str)> = table
+            .iter()
+            .map(|entry| (entry.text.as_str(), entry.trace[0].transform.as_str()))
+            .collect();
+        assert_eq!(rows.len(), table.len());
+    }
+
+    #[test]
+    fn condition_names_from_flags() {
+        let mlt = MultiLanguageTransformer::default();
+        let flags = mlt.get_condition_flags_from_condition_type("en", "v_phr");
+        let names = mlt.condition_names_from_flags("en", flags, None);
+        // `v`'s only sub-condition is `v_phr`, so they share the same flags
+        assert_eq!(names.len(), 1);
+        assert_eq!(names[0].name, "Verb");
+        assert!(mlt.condition_names_from_flags("xx", flags, None).is_empty());
+    }
+
+    #[test]
+    fn transform_with_options() {
+        let mlt = MultiLanguageTransformer::default();
+        let full = mlt.transform("ja", "食べさせられませんでした");
+
+        let unbounded = mlt.transform_with_options(
+            "ja",
+            "食べさせられませんでした",
+            &TransformOptions::default(),
+        );
+        assert!(!unbounded.truncated);
+        passert_eq!(unbounded.results, full);
+
+        let options = TransformOptions {
+            max_depth: Some(2),
+            ..Default::default()
+        };
+        let shallow = mlt.transform_with_options("ja", "食べさせられませんでした", &options);
+        assert!(shallow.truncated);
+        assert!(shallow.results.iter().all(|tt| tt.trace.len() <= 2));
+        let expected: Vec<_> = full
+            .iter()
+            .filter(|tt| tt.trace.len() <= 2)
+            .cloned()
+            .collect();
+        passert_eq!(shallow.results, expected);
+
+        let options = TransformOptions {
+            max_candidates: Some(5),
+            ..Default::default()
+        };
+        let few = mlt.transform_with_options("ja", "食べさせられませんでした", &options);
+        assert!(few.truncated);
+        passert_eq!(few.results, full[..5]);
+
+        for max_candidates in [0, 1] {
+            let options = TransformOptions {
+                max_candidates: Some(max_candidates),
+                ..Default::default()
+            };
+            let capped = mlt.transform_with_options("ja", "食べさせられませんでした", &options);
+            assert!(capped.truncated);
+            passert_eq!(capped.results, full[..max_candidates]);
+        }
+
+        let options = TransformOptions {
+            deadline: Some(std::time::Instant::now()),
+            ..Default::default()
+        };
+        let expired = mlt.transform_with_options("ja", "食べさせられませんでした", &options);
+        assert!(expired.truncated);
+        assert_eq!(expired.results.len(), 1);
+    }
+
+    #[test]
+    fn transform_iter() {
+        let mlt = MultiLanguageTransformer::default();
+        for (language, text) in [
+            ("ja", "食べさせられませんでした"),
+            ("en", "unwalked"),
+            ("xx", "foo"),
+        ] {
+            let lazy: Vec<TransformedText> = mlt.transform_iter(language, text).collect();
+            passert_eq!(lazy, mlt.transform(language, text));
+        }
+        let first = mlt
+            .transform_iter("ja", "流れて")
+            .find(|tt| tt.text == "流れる");
+        assert!(first.is_some());
+    }
+
+    #[test]
+    fn transform_with_lookup() {
+        let mlt = MultiLanguageTransformer::default();
+        let pos = |pos: &[&str]| pos.iter().map(|p| p.to_string()).collect::<Vec<String>>();
+        let dictionary: HashMap<String, Vec<Vec<String>>> = HashMap::from([
+            // the `adj-i` entry doesn't match the candidate's conditions
+            ("食べる".to_string(), vec![pos(&["adj-i"]), pos(&["v1"])]),
+            ("食べ".to_string(), vec![pos(&["n"])]),
+        ]);
+        let matches = mlt.transform_with_lookup("ja", "食べました", &dictionary);
+        let terms: Vec<&str> = matches.iter().map(|(tt, _)| tt.text.as_str()).collect();
+        assert_eq!(terms, ["食べる"]);
+        let (candidate, entry) = &matches[0];
+        assert_eq!(candidate.trace.len(), 2);
+        assert_eq!(entry, &pos(&["v1"]));
+
+        // no transformer: every entry of the source text is kept
+        let matches = mlt.transform_with_lookup("xx", "食べる", &dictionary);
+        assert_eq!(matches.len(), 2);
+    }
+
+    #[test]
+    fn transform_ranked() {
+        let mlt = MultiLanguageTransformer::default();
+        for (language, text, expected) in [
+            ("ja", "食べさせられた", "食べる"),
+            ("ja", "読んでいない", "読む"),
+            ("en", "walked", "walk"),
+            ("es", "hablamos", "hablar"),
+        ] {
+            let ranked = mlt.transform_ranked(language, text, &RankingOptions::default());
+            assert_eq!(ranked.len(), mlt.transform(language, text).len());
+            assert!(ranked.windows(2).all(|w| w[0].score >= w[1].score));
+            // without a dictionary, `読んでいない` is as likely to be `読ぬ` or `読ぶ`
+            let top_score = ranked[0].score;
+            assert!(
+                ranked
+                    .iter()
+                    .take_while(|r| r.score == top_score)
+                    .any(|r| r.transformed_text.text == expected),
+                "{text}"
+            );
+            // the untouched source text is never a dictionary form candidate
+            assert_eq!(ranked.last().unwrap().transformed_text.text, text);
+        }
+    }
+
     #[test]
     fn transform_es() {
         let mlt = MultiLanguageTransformer::default();
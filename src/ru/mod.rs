@@ -0,0 +1,2 @@
+pub mod ru_transforms;
+pub mod russian;
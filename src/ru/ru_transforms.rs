@@ -0,0 +1,965 @@
+use indexmap::IndexMap;
+use std::sync::LazyLock;
+
+use crate::{
+    ja::ja_transforms::{LanguageTransformerTestCase, TransformTest},
+    transformer::{
+        Condition, ConditionMap, LanguageTransformDescriptor, Rule, RuleType, Transform,
+        TransformMap,
+    },
+    transforms::inflection,
+};
+
+/// `(inflected, deinflected)` ending pairs.
+type Endings = &'static [(&'static str, &'static str)];
+
+/// `(inflected, infinitive, reflexive infinitive)` ending triples.
+type VerbEndings = &'static [(&'static str, &'static str, &'static str)];
+
+/// Last letter of a masculine short adjective, which has no ending: `нов` -> `новый`.
+/// Velars & hushing consonants are followed by -ий, other consonants by -ый.
+const SHORT_ADJECTIVE_FINALS: Endings = &[
+    ("б", "бый"),
+    ("в", "вый"),
+    ("д", "дый"),
+    ("з", "зый"),
+    ("л", "лый"),
+    ("м", "мый"),
+    ("н", "ный"),
+    ("п", "пый"),
+    ("р", "рый"),
+    ("с", "сый"),
+    ("т", "тый"),
+    ("г", "гий"),
+    ("к", "кий"),
+    ("х", "хий"),
+    ("ж", "жий"),
+    ("ш", "ший"),
+    ("ч", "чий"),
+    ("щ", "щий"),
+];
+
+/// Case endings of a noun and an adjective, which share their case transforms.
+fn case_rules(noun: Endings, adjective: Endings) -> Vec<Rule> {
+    let nouns = noun.iter().map(|&(inflected, deinflected)| {
+        inflection(
+            inflected,
+            deinflected,
+            &["n_case"],
+            &["n"],
+            RuleType::Suffix,
+        )
+    });
+    let adjectives = adjective.iter().map(|&(inflected, deinflected)| {
+        inflection(
+            inflected,
+            deinflected,
+            &["adj_case"],
+            &["adj"],
+            RuleType::Suffix,
+        )
+    });
+    nouns.chain(adjectives).collect()
+}
+
+/// Endings of one verb form, each with a reflexive twin for when
+/// the `reflexive` transform already removed -ся / -сь:
+/// `учу` -> `учить`, but `учу(сь)` -> `учиться`.
+fn verb_rules(
+    endings: VerbEndings,
+    conditions_in: &'static [&'static str],
+    reflexive_conditions_in: &'static [&'static str],
+) -> Vec<Rule> {
+    endings
+        .iter()
+        .flat_map(|&(inflected, infinitive, reflexive_infinitive)| {
+            [
+                inflection(
+                    inflected,
+                    infinitive,
+                    conditions_in,
+                    &["v"],
+                    RuleType::Suffix,
+                ),
+                inflection(
+                    inflected,
+                    reflexive_infinitive,
+                    reflexive_conditions_in,
+                    &["v"],
+                    RuleType::Suffix,
+                ),
+            ]
+        })
+        .collect()
+}
+
+pub static RUSSIAN_TRANSFORMS_DESCRIPTOR: LazyLock<LanguageTransformDescriptor> =
+    LazyLock::new(|| LanguageTransformDescriptor {
+        language: "ru",
+        conditions: &RU_CONDITIONS_MAP,
+        transforms: &RU_TRANSFORMS_MAP,
+    });
+
+pub static RU_CONDITIONS_MAP: LazyLock<ConditionMap> = LazyLock::new(|| {
+    ConditionMap(IndexMap::from([
+        (
+            "n",
+            Condition {
+                name: "Noun",
+                is_dictionary_form: true,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "n_case",
+            Condition {
+                name: "Noun case form",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "adj",
+            Condition {
+                name: "Adjective",
+                is_dictionary_form: true,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "adj_case",
+            Condition {
+                name: "Adjective case form",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "adj_short",
+            Condition {
+                name: "Short adjective",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "adj_cmp",
+            Condition {
+                name: "Comparative adjective",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v",
+            Condition {
+                name: "Verb",
+                is_dictionary_form: true,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v_pres",
+            Condition {
+                name: "Present tense verb",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v_past",
+            Condition {
+                name: "Past tense verb",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v_imp",
+            Condition {
+                name: "Imperative verb",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v_refl",
+            Condition {
+                name: "Reflexive verb",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v_pres_refl",
+            Condition {
+                name: "Present tense reflexive verb without -ся",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v_past_refl",
+            Condition {
+                name: "Past tense reflexive verb without -ся",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+        (
+            "v_imp_refl",
+            Condition {
+                name: "Imperative reflexive verb without -ся",
+                is_dictionary_form: false,
+                sub_conditions: None,
+                i18n: None,
+            },
+        ),
+    ]))
+});
+
+pub static RU_TRANSFORMS_MAP: LazyLock<TransformMap> = LazyLock::new(|| {
+    TransformMap(IndexMap::from([
+        (
+            "genitive",
+            Transform {
+                name: "genitive",
+                description: Some("Genitive singular"),
+                rules: case_rules(
+                    &[
+                        ("а", ""),
+                        ("я", "ь"),
+                        ("я", "й"),
+                        ("ы", "а"),
+                        ("и", "а"),
+                        ("и", "я"),
+                        ("и", "ь"),
+                        ("а", "о"),
+                        ("я", "е"),
+                    ],
+                    &[
+                        ("ого", "ый"),
+                        ("ого", "ой"),
+                        ("ого", "ий"),
+                        ("его", "ий"),
+                        ("ой", "ый"),
+                        ("ой", "ий"),
+                        ("ей", "ий"),
+                    ],
+                ),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "dative",
+            Transform {
+                name: "dative",
+                description: Some("Dative singular"),
+                rules: case_rules(
+                    &[
+                        ("у", ""),
+                        ("ю", "ь"),
+                        ("ю", "й"),
+                        ("е", "а"),
+                        ("е", "я"),
+                        ("и", "ь"),
+                        ("у", "о"),
+                        ("ю", "е"),
+                    ],
+                    &[("ому", "ый"), ("ому", "ой"), ("ому", "ий"), ("ему", "ий")],
+                ),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "accusative",
+            Transform {
+                name: "accusative",
+                description: Some("Accusative singular"),
+                rules: case_rules(
+                    &[("у", "а"), ("ю", "я")],
+                    &[("ую", "ый"), ("ую", "ой"), ("ую", "ий"), ("юю", "ий")],
+                ),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "instrumental",
+            Transform {
+                name: "instrumental",
+                description: Some("Instrumental singular"),
+                rules: case_rules(
+                    &[
+                        ("ом", ""),
+                        ("ем", "ь"),
+                        ("ем", "й"),
+                        ("ой", "а"),
+                        ("ою", "а"),
+                        ("ей", "я"),
+                        ("ью", "ь"),
+                        ("ом", "о"),
+                        ("ем", "е"),
+                    ],
+                    &[("ым", "ый"), ("ым", "ой"), ("им", "ой"), ("им", "ий")],
+                ),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "prepositional",
+            Transform {
+                name: "prepositional",
+                description: Some("Prepositional singular"),
+                rules: case_rules(
+                    &[
+                        ("е", ""),
+                        ("е", "ь"),
+                        ("е", "й"),
+                        ("е", "а"),
+                        ("е", "я"),
+                        ("и", "ь"),
+                        ("е", "о"),
+                    ],
+                    &[("ом", "ый"), ("ом", "ой"), ("ом", "ий"), ("ем", "ий")],
+                ),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "plural",
+            Transform {
+                name: "plural",
+                description: Some("Nominative plural"),
+                rules: case_rules(
+                    &[
+                        ("ы", ""),
+                        ("и", ""),
+                        ("и", "ь"),
+                        ("и", "й"),
+                        ("ы", "а"),
+                        ("и", "а"),
+                        ("и", "я"),
+                        ("а", "о"),
+                        ("я", "е"),
+                    ],
+                    &[("ые", "ый"), ("ые", "ой"), ("ие", "ой"), ("ие", "ий")],
+                ),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "genitive plural",
+            Transform {
+                name: "genitive plural",
+                description: Some("Genitive plural"),
+                rules: case_rules(
+                    &[
+                        ("ов", ""),
+                        ("ев", "й"),
+                        ("ей", ""),
+                        ("ей", "ь"),
+                        ("ей", "е"),
+                        ("", "а"),
+                        ("", "о"),
+                        ("ь", "я"),
+                    ],
+                    &[("ых", "ый"), ("ых", "ой"), ("их", "ой"), ("их", "ий")],
+                ),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "dative plural",
+            Transform {
+                name: "dative plural",
+                description: Some("Dative plural"),
+                rules: case_rules(
+                    &[
+                        ("ам", ""),
+                        ("ям", "ь"),
+                        ("ям", "й"),
+                        ("ам", "а"),
+                        ("ям", "я"),
+                        ("ам", "о"),
+                        ("ям", "е"),
+                    ],
+                    &[],
+                ),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "instrumental plural",
+            Transform {
+                name: "instrumental plural",
+                description: Some("Instrumental plural"),
+                rules: case_rules(
+                    &[
+                        ("ами", ""),
+                        ("ями", "ь"),
+                        ("ями", "й"),
+                        ("ами", "а"),
+                        ("ями", "я"),
+                        ("ами", "о"),
+                        ("ями", "е"),
+                    ],
+                    &[("ыми", "ый"), ("ыми", "ой"), ("ими", "ой"), ("ими", "ий")],
+                ),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "prepositional plural",
+            Transform {
+                name: "prepositional plural",
+                description: Some("Prepositional plural"),
+                rules: case_rules(
+                    &[
+                        ("ах", ""),
+                        ("ях", "ь"),
+                        ("ях", "й"),
+                        ("ах", "а"),
+                        ("ях", "я"),
+                        ("ах", "о"),
+                        ("ях", "е"),
+                    ],
+                    &[],
+                ),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "feminine",
+            Transform {
+                name: "feminine",
+                description: Some("Feminine form of an adjective"),
+                rules: case_rules(
+                    &[],
+                    &[("ая", "ый"), ("ая", "ой"), ("ая", "ий"), ("яя", "ий")],
+                ),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "neuter",
+            Transform {
+                name: "neuter",
+                description: Some("Neuter form of an adjective"),
+                rules: case_rules(
+                    &[],
+                    &[("ое", "ый"), ("ое", "ой"), ("ое", "ий"), ("ее", "ий")],
+                ),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "short adjective",
+            Transform {
+                name: "short adjective",
+                description: Some("Short (predicative) form of an adjective"),
+                rules: SHORT_ADJECTIVE_FINALS
+                    .iter()
+                    .map(|&(inflected, deinflected)| {
+                        inflection(
+                            inflected,
+                            deinflected,
+                            &["adj_short"],
+                            &["adj"],
+                            RuleType::Suffix,
+                        )
+                    })
+                    .chain([
+                        inflection("а", "ый", &["adj_short"], &["adj"], RuleType::Suffix),
+                        inflection("о", "ый", &["adj_short"], &["adj"], RuleType::Suffix),
+                        inflection("ы", "ый", &["adj_short"], &["adj"], RuleType::Suffix),
+                        inflection("а", "ий", &["adj_short"], &["adj"], RuleType::Suffix),
+                        inflection("о", "ий", &["adj_short"], &["adj"], RuleType::Suffix),
+                        inflection("и", "ий", &["adj_short"], &["adj"], RuleType::Suffix),
+                        inflection("ен", "ный", &["adj_short"], &["adj"], RuleType::Suffix),
+                    ])
+                    .collect(),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "comparative",
+            Transform {
+                name: "comparative",
+                description: Some("Comparative form of an adjective"),
+                rules: vec![
+                    inflection("ее", "ый", &["adj_cmp"], &["adj"], RuleType::Suffix),
+                    inflection("ей", "ый", &["adj_cmp"], &["adj"], RuleType::Suffix),
+                    inflection("ее", "ий", &["adj_cmp"], &["adj"], RuleType::Suffix),
+                    inflection("же", "гий", &["adj_cmp"], &["adj"], RuleType::Suffix),
+                    inflection("же", "зкий", &["adj_cmp"], &["adj"], RuleType::Suffix),
+                    inflection("че", "кий", &["adj_cmp"], &["adj"], RuleType::Suffix),
+                    inflection("ще", "стый", &["adj_cmp"], &["adj"], RuleType::Suffix),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "present",
+            Transform {
+                name: "present",
+                description: Some("Present (or perfective future) tense of a verb"),
+                rules: verb_rules(
+                    &[
+                        // 1st conjugation
+                        ("аю", "ать", "аться"),
+                        ("аешь", "ать", "аться"),
+                        ("ает", "ать", "аться"),
+                        ("аем", "ать", "аться"),
+                        ("аете", "ать", "аться"),
+                        ("ают", "ать", "аться"),
+                        ("яю", "ять", "яться"),
+                        ("яешь", "ять", "яться"),
+                        ("яет", "ять", "яться"),
+                        ("яем", "ять", "яться"),
+                        ("яете", "ять", "яться"),
+                        ("яют", "ять", "яться"),
+                        ("ею", "еть", "еться"),
+                        ("еешь", "еть", "еться"),
+                        ("еет", "еть", "еться"),
+                        ("еем", "еть", "еться"),
+                        ("еете", "еть", "еться"),
+                        ("еют", "еть", "еться"),
+                        ("ую", "овать", "оваться"),
+                        ("уешь", "овать", "оваться"),
+                        ("ует", "овать", "оваться"),
+                        ("уем", "овать", "оваться"),
+                        ("уете", "овать", "оваться"),
+                        ("уют", "овать", "оваться"),
+                        ("у", "ти", "тись"),
+                        ("ешь", "ти", "тись"),
+                        ("ет", "ти", "тись"),
+                        ("ем", "ти", "тись"),
+                        ("ете", "ти", "тись"),
+                        ("ут", "ти", "тись"),
+                        // 2nd conjugation
+                        ("ю", "ить", "иться"),
+                        ("у", "ить", "иться"),
+                        ("ишь", "ить", "иться"),
+                        ("ит", "ить", "иться"),
+                        ("им", "ить", "иться"),
+                        ("ите", "ить", "иться"),
+                        ("ят", "ить", "иться"),
+                        ("ат", "ить", "иться"),
+                        ("ю", "еть", "еться"),
+                        ("ишь", "еть", "еться"),
+                        ("ит", "еть", "еться"),
+                        ("им", "еть", "еться"),
+                        ("ите", "еть", "еться"),
+                        ("ят", "еть", "еться"),
+                        ("у", "ать", "аться"),
+                        ("ишь", "ать", "аться"),
+                        ("ит", "ать", "аться"),
+                        ("им", "ать", "аться"),
+                        ("ите", "ать", "аться"),
+                        ("ат", "ать", "аться"),
+                        // 1st person singular consonant mutations
+                        ("блю", "бить", "биться"),
+                        ("влю", "вить", "виться"),
+                        ("плю", "пить", "питься"),
+                        ("млю", "мить", "миться"),
+                        ("жу", "дить", "диться"),
+                        ("жу", "зить", "зиться"),
+                        ("жу", "деть", "деться"),
+                        ("чу", "тить", "титься"),
+                        ("шу", "сить", "ситься"),
+                        ("щу", "стить", "ститься"),
+                    ],
+                    &["v_pres"],
+                    &["v_pres_refl"],
+                ),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "past",
+            Transform {
+                name: "past",
+                description: Some("Past tense of a verb"),
+                rules: verb_rules(
+                    &[
+                        ("л", "ть", "ться"),
+                        ("ла", "ть", "ться"),
+                        ("ло", "ть", "ться"),
+                        ("ли", "ть", "ться"),
+                        ("ла", "ти", "тись"),
+                        ("ло", "ти", "тись"),
+                        ("ли", "ти", "тись"),
+                    ],
+                    &["v_past"],
+                    &["v_past_refl"],
+                ),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "imperative",
+            Transform {
+                name: "imperative",
+                description: Some("Imperative form of a verb"),
+                rules: verb_rules(
+                    &[
+                        ("ай", "ать", "аться"),
+                        ("айте", "ать", "аться"),
+                        ("яй", "ять", "яться"),
+                        ("яйте", "ять", "яться"),
+                        ("ей", "еть", "еться"),
+                        ("ейте", "еть", "еться"),
+                        ("уй", "овать", "оваться"),
+                        ("уйте", "овать", "оваться"),
+                        ("и", "ить", "иться"),
+                        ("ите", "ить", "иться"),
+                        ("ь", "ить", "иться"),
+                        ("ьте", "ить", "иться"),
+                    ],
+                    &["v_imp"],
+                    &["v_imp_refl"],
+                ),
+                i18n: None,
+                weight: None,
+            },
+        ),
+        (
+            "reflexive",
+            Transform {
+                name: "reflexive",
+                description: Some("Reflexive verb ending -ся / -сь"),
+                rules: vec![
+                    inflection(
+                        "ся",
+                        "",
+                        &["v_refl"],
+                        &["v_pres_refl", "v_past_refl", "v_imp_refl"],
+                        RuleType::Suffix,
+                    ),
+                    inflection(
+                        "сь",
+                        "",
+                        &["v_refl"],
+                        &["v_pres_refl", "v_past_refl", "v_imp_refl"],
+                        RuleType::Suffix,
+                    ),
+                ],
+                i18n: None,
+                weight: None,
+            },
+        ),
+    ]))
+});
+
+pub(crate) static RU_TRANSFORM_TESTS: LazyLock<[&[TransformTest]; 3]> =
+    LazyLock::new(|| [&*RU_NOUN_TESTS, &*RU_ADJECTIVE_TESTS, &*RU_VERB_TESTS]);
+
+pub(crate) static RU_NOUN_TESTS: LazyLock<[TransformTest; 4]> = LazyLock::new(|| {
+    [
+        TransformTest {
+            term: "стол",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "стола",
+                    rule: "n",
+                    reasons: vec!["genitive"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "столом",
+                    rule: "n",
+                    reasons: vec!["instrumental"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "столов",
+                    rule: "n",
+                    reasons: vec!["genitive plural"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "столах",
+                    rule: "n",
+                    reasons: vec!["prepositional plural"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "книга",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "книгу",
+                    rule: "n",
+                    reasons: vec!["accusative"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "книгой",
+                    rule: "n",
+                    reasons: vec!["instrumental"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "книгам",
+                    rule: "n",
+                    reasons: vec!["dative plural"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "тетрадь",
+            sources: vec![LanguageTransformerTestCase {
+                inner: "тетрадью",
+                rule: "n",
+                reasons: vec!["instrumental"],
+            }],
+        },
+        TransformTest {
+            term: "окно",
+            sources: vec![LanguageTransformerTestCase {
+                inner: "окнами",
+                rule: "n",
+                reasons: vec!["instrumental plural"],
+            }],
+        },
+    ]
+});
+
+pub(crate) static RU_ADJECTIVE_TESTS: LazyLock<[TransformTest; 5]> = LazyLock::new(|| {
+    [
+        TransformTest {
+            term: "новый",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "новая",
+                    rule: "adj",
+                    reasons: vec!["feminine"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "нового",
+                    rule: "adj",
+                    reasons: vec!["genitive"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "новыми",
+                    rule: "adj",
+                    reasons: vec!["instrumental plural"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "нов",
+                    rule: "adj",
+                    reasons: vec!["short adjective"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "новее",
+                    rule: "adj",
+                    reasons: vec!["comparative"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "синий",
+            sources: vec![LanguageTransformerTestCase {
+                inner: "синюю",
+                rule: "adj",
+                reasons: vec!["accusative"],
+            }],
+        },
+        TransformTest {
+            term: "близкий",
+            sources: vec![LanguageTransformerTestCase {
+                inner: "ближе",
+                rule: "adj",
+                reasons: vec!["comparative"],
+            }],
+        },
+        TransformTest {
+            term: "молодой",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "молодым",
+                    rule: "adj",
+                    reasons: vec!["instrumental"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "молодые",
+                    rule: "adj",
+                    reasons: vec!["plural"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "молодых",
+                    rule: "adj",
+                    reasons: vec!["genitive plural"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "молодыми",
+                    rule: "adj",
+                    reasons: vec!["instrumental plural"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "высокий",
+            sources: vec![LanguageTransformerTestCase {
+                inner: "высок",
+                rule: "adj",
+                reasons: vec!["short adjective"],
+            }],
+        },
+    ]
+});
+
+pub(crate) static RU_VERB_TESTS: LazyLock<[TransformTest; 6]> = LazyLock::new(|| {
+    [
+        TransformTest {
+            term: "читать",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "читаю",
+                    rule: "v",
+                    reasons: vec!["present"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "читают",
+                    rule: "v",
+                    reasons: vec!["present"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "читала",
+                    rule: "v",
+                    reasons: vec!["past"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "читайте",
+                    rule: "v",
+                    reasons: vec!["imperative"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "говорить",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "говоришь",
+                    rule: "v",
+                    reasons: vec!["present"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "говорили",
+                    rule: "v",
+                    reasons: vec!["past"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "учиться",
+            sources: vec![
+                LanguageTransformerTestCase {
+                    inner: "учусь",
+                    rule: "v",
+                    reasons: vec!["present", "reflexive"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "учится",
+                    rule: "v",
+                    reasons: vec!["present", "reflexive"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "училась",
+                    rule: "v",
+                    reasons: vec!["past", "reflexive"],
+                },
+                LanguageTransformerTestCase {
+                    inner: "учись",
+                    rule: "v",
+                    reasons: vec!["imperative", "reflexive"],
+                },
+            ],
+        },
+        TransformTest {
+            term: "любить",
+            sources: vec![LanguageTransformerTestCase {
+                inner: "люблю",
+                rule: "v",
+                reasons: vec!["present"],
+            }],
+        },
+        TransformTest {
+            term: "рисовать",
+            sources: vec![LanguageTransformerTestCase {
+                inner: "рисуют",
+                rule: "v",
+                reasons: vec!["present"],
+            }],
+        },
+        TransformTest {
+            term: "нести",
+            sources: vec![LanguageTransformerTestCase {
+                inner: "несут",
+                rule: "v",
+                reasons: vec!["present"],
+            }],
+        },
+    ]
+});
+
+#[cfg(test)]
+mod rutransforms {
+    use crate::{
+        ja::ja_transforms::has_term_reasons,
+        ru::ru_transforms::{RUSSIAN_TRANSFORMS_DESCRIPTOR, RU_TRANSFORM_TESTS},
+        transformer::LanguageTransformer,
+    };
+
+    #[test]
+    fn transforms() {
+        let mut lt = LanguageTransformer::new();
+        lt.add_descriptor(&RUSSIAN_TRANSFORMS_DESCRIPTOR).unwrap();
+
+        for test_vec in RU_TRANSFORM_TESTS.into_iter() {
+            for test in test_vec {
+                let term = test.term;
+                for case in &test.sources {
+                    let source = case.inner;
+                    let rule = case.rule;
+                    let expected_reasons = &case.reasons;
+
+                    let result =
+                        has_term_reasons(&lt, source, term, Some(rule), Some(expected_reasons));
+                    if let Err(e) = result {
+                        panic!("Failed: {e}");
+                    }
+                }
+            }
+        }
+        // a word ending in a vowel isn't taken for a masculine short adjective
+        assert!(lt
+            .transform("читаю")
+            .iter()
+            .all(|tt| !tt.text.ends_with("ый") && !tt.text.ends_with("ий")));
+    }
+}
@@ -0,0 +1,72 @@
+use unicode_normalization::UnicodeNormalization;
+
+use crate::cjk_utils::{is_code_point_in_ranges, CodepointRange};
+
+pub const CYRILLIC_RANGE: CodepointRange = (0x0400, 0x04ff);
+pub const CYRILLIC_SUPPLEMENT_RANGE: CodepointRange = (0x0500, 0x052f);
+
+pub const RUSSIAN_RANGES: [CodepointRange; 2] = [CYRILLIC_RANGE, CYRILLIC_SUPPLEMENT_RANGE];
+
+const COMBINING_ACUTE_ACCENT: char = '\u{0301}';
+const COMBINING_GRAVE_ACCENT: char = '\u{0300}';
+
+/// Checks if a string contains at least one Cyrillic character.
+pub fn is_string_partially_russian(s: &str) -> bool {
+    s.chars()
+        .any(|c| is_code_point_in_ranges(c as u32, &RUSSIAN_RANGES))
+}
+
+/// Removes the stress marks used in learner material and writes ё as е,
+/// which is how most Russian text is printed.
+///
+/// `молоко́` -> `молоко`, `ёлка` -> `елка`
+pub fn remove_russian_diacritics(s: &str) -> String {
+    s.nfd()
+        .filter(|&c| c != COMBINING_ACUTE_ACCENT && c != COMBINING_GRAVE_ACCENT)
+        .nfc()
+        .map(|c| match c {
+            'ё' => 'е',
+            'Ё' => 'Е',
+            c => c,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod ru_tests {
+    use super::*;
+
+    #[test]
+    fn partially_russian() {
+        assert!(is_string_partially_russian("читать"));
+        assert!(is_string_partially_russian("to read: читать"));
+        assert!(!is_string_partially_russian("chitat"));
+    }
+
+    #[test]
+    fn diacritics() {
+        assert_eq!(remove_russian_diacritics("молоко́"), "молоко");
+        assert_eq!(remove_russian_diacritics("ёлка"), "елка");
+        assert_eq!(remove_russian_diacritics("Ёж"), "Еж");
+        // stressed ё, written decomposed
+        assert_eq!(remove_russian_diacritics("е\u{0308}\u{0301}лка"), "елка");
+        // й decomposes to и + U+0306, which must survive
+        assert_eq!(remove_russian_diacritics("мой"), "мой");
+    }
+
+    #[test]
+    fn language_descriptor() {
+        use crate::languages::{get_all_language_text_processors, is_text_lookup_worthy};
+
+        assert!(is_text_lookup_worthy("читаю", "ru"));
+        assert!(!is_text_lookup_worthy("chitayu", "ru"));
+        let processors = get_all_language_text_processors()
+            .into_iter()
+            .find(|p| p.iso == "ru")
+            .unwrap();
+        let text = processors.pre.iter().fold("Ёлки́".to_string(), |text, p| {
+            (p.processor.process)(&text, p.processor.options[1].clone())
+        });
+        assert_eq!(text, "елки");
+    }
+}
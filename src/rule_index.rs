@@ -0,0 +1,126 @@
+//! A literal lookup index over a [`LanguageTransformer`]'s rules.
+//!
+//! [`LanguageTransformer::transform`] normally tests each transform's joined `heuristic` regex
+//! and then every rule's `is_inflected` regex for every candidate text.
+//! Almost all rules are plain literal `Suffix`, `Prefix` or `WholeWord` rules though,
+//! so [`RuleIndex`] stores them in a reversed-suffix trie, a prefix trie and a whole word map,
+//! and finds every applicable rule for a text in a single walk over its characters.
+//! Rules whose pattern isn't a plain literal (ie: `RuleType::Other`) are still tested with regex.
+//!
+//! [`LanguageTransformer`]: crate::transformer::LanguageTransformer
+//! [`LanguageTransformer::transform`]: crate::transformer::LanguageTransformer::transform
+
+use std::collections::HashMap;
+
+use crate::transformer::{DeinflectFnType, InternalRule, InternalTransform, RuleType};
+
+/// `(transform index, rule index)` of a rule in `LanguageTransformer::transforms`.
+pub type RuleId = (usize, usize);
+
+#[derive(Debug, Clone, Default)]
+struct TrieNode {
+    children: HashMap<char, TrieNode>,
+    rules: Vec<RuleId>,
+}
+
+impl TrieNode {
+    fn insert(&mut self, key: impl Iterator<Item = char>, id: RuleId) {
+        let node = key.fold(self, |node, c| node.children.entry(c).or_default());
+        node.rules.push(id);
+    }
+
+    /// Collects the rules of every node along `key`, including the root.
+    fn collect(&self, key: impl Iterator<Item = char>, out: &mut Vec<RuleId>) {
+        out.extend_from_slice(&self.rules);
+        let mut node = self;
+        for c in key {
+            let Some(next) = node.children.get(&c) else {
+                return;
+            };
+            out.extend_from_slice(&next.rules);
+            node = next;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct RuleIndex {
+    /// `Suffix` rules keyed on their reversed `inflected_str`.
+    suffixes: TrieNode,
+    /// `Prefix` rules keyed on their `inflected_str`.
+    prefixes: TrieNode,
+    whole_words: HashMap<String, Vec<RuleId>>,
+    /// Rules that can only be matched with their `is_inflected` regex.
+    fallback: Vec<RuleId>,
+}
+
+impl RuleIndex {
+    pub fn new(transforms: &[InternalTransform]) -> Self {
+        let mut index = Self::default();
+        for (i, transform) in transforms.iter().enumerate() {
+            for (j, rule) in transform.rules.iter().enumerate() {
+                let id = (i, j);
+                match (rule.rule_type, literal_inflected(rule)) {
+                    (RuleType::Suffix, Some(inflected)) => {
+                        index.suffixes.insert(inflected.chars().rev(), id)
+                    }
+                    (RuleType::Prefix, Some(inflected)) => {
+                        index.prefixes.insert(inflected.chars(), id)
+                    }
+                    (RuleType::WholeWord, Some(inflected)) => index
+                        .whole_words
+                        .entry(inflected.to_string())
+                        .or_default()
+                        .push(id),
+                    _ => index.fallback.push(id),
+                }
+            }
+        }
+        index
+    }
+
+    /// Every rule whose `is_inflected` regex matches `text`,
+    /// sorted in the order [`LanguageTransformer::transform`] visits them.
+    ///
+    /// [`LanguageTransformer::transform`]: crate::transformer::LanguageTransformer::transform
+    pub fn matching_rules(&self, text: &str, transforms: &[InternalTransform]) -> Vec<RuleId> {
+        let mut ids = Vec::new();
+        self.suffixes.collect(text.chars().rev(), &mut ids);
+        self.prefixes.collect(text.chars(), &mut ids);
+        if let Some(whole_word) = self.whole_words.get(text) {
+            ids.extend_from_slice(whole_word);
+        }
+        ids.extend(self.fallback.iter().copied().filter(|&(i, j)| {
+            transforms[i].rules[j]
+                .is_inflected
+                .is_match(text)
+                .unwrap_or(false)
+        }));
+        ids.sort_unstable();
+        ids
+    }
+}
+
+/// The rule's `inflected_str`, if its `is_inflected` regex is exactly that literal
+/// anchored the way [`crate::transforms::inflection`] anchors it.
+fn literal_inflected(rule: &InternalRule) -> Option<&str> {
+    let inflected = rule.inflected_str.as_deref()?;
+    if inflected.chars().any(is_regex_meta_character) {
+        return None;
+    }
+    let (pattern, deinflect_fn) = match rule.rule_type {
+        RuleType::Suffix => (format!("{inflected}$"), DeinflectFnType::GenericSuffix),
+        RuleType::Prefix => (format!("^{inflected}"), DeinflectFnType::GenericPrefix),
+        RuleType::WholeWord => (format!("^{inflected}$"), DeinflectFnType::GenericWholeWord),
+        RuleType::Other => return None,
+    };
+    (rule.is_inflected.as_str() == pattern && rule.deinflect_fn == deinflect_fn)
+        .then_some(inflected)
+}
+
+fn is_regex_meta_character(c: char) -> bool {
+    matches!(
+        c,
+        '\\' | '.' | '+' | '*' | '?' | '(' | ')' | '|' | '[' | ']' | '{' | '}' | '^' | '$'
+    )
+}
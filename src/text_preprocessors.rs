@@ -1,16 +1,30 @@
 use crate::{
+    ar::arabic::{
+        add_hamza_bottom, add_hamza_top, convert_alef_maksura_to_yeh, normalize_alef_variants,
+    },
+    arabic_utils::{remove_arabic_script_diacritics, remove_tatweel},
+    fa::persian::{
+        convert_arabic_to_persian_characters, convert_persian_to_arabic_characters,
+        remove_zero_width_non_joiner,
+    },
     ja::japanese::{
         collapse_emphatic_sequences, convert_alphanumeric_to_fullwidth,
         convert_fullwidth_alphanumeric_to_normal, convert_halfwidth_kana_to_fullwidth,
         convert_hiragana_to_katakana, convert_katakana_to_hiragana,
         normalize_cjk_compatibility_characters, normalize_combining_characters,
     },
+    ko::korean::{disassemble_hangul, reassemble_hangul},
     language_d::{
         BidirectionalConversionPreProcessor, BidirectionalPreProcessorOptions, TextProcessor,
         TextProcessorSetting,
     },
+    ru::russian::remove_russian_diacritics,
     text_processors::BASIC_TEXT_PROCESSOR_OPTIONS,
     wanakana::convert_alphabetic_to_kana,
+    zh::{
+        chinese::{convert_simplified_to_traditional, convert_traditional_to_simplified},
+        pinyin::{pinyin_marks_to_numbers, pinyin_numbers_to_marks, pinyin_to_toneless},
+    },
 };
 
 use kanji_processor::convert_variants;
@@ -160,3 +174,226 @@ pub const STANDARDIZE_KANJI: TextProcessor = TextProcessor {
     options: BASIC_TEXT_PROCESSOR_OPTIONS,
     process: standardize_kanji_helper,
 };
+
+fn process_chinese_variants(s: &str, setting: TextProcessorSetting) -> String {
+    match setting {
+        TextProcessorSetting::BiDirectional(opt) => match opt {
+            BidirectionalPreProcessorOptions::Off => s.to_string(),
+            BidirectionalPreProcessorOptions::Direct => convert_traditional_to_simplified(s),
+            BidirectionalPreProcessorOptions::Inverse => convert_simplified_to_traditional(s),
+        },
+        _ => s.to_string(),
+    }
+}
+
+pub const CONVERT_CHINESE_VARIANTS: BidirectionalConversionPreProcessor =
+    BidirectionalConversionPreProcessor {
+        name: "Convert Traditional Chinese to Simplified Chinese",
+        description: "這個國家 → 这个国家 and vice versa",
+        options: &[
+            TextProcessorSetting::BiDirectional(BidirectionalPreProcessorOptions::Off),
+            TextProcessorSetting::BiDirectional(BidirectionalPreProcessorOptions::Direct),
+            TextProcessorSetting::BiDirectional(BidirectionalPreProcessorOptions::Inverse),
+        ],
+        process: process_chinese_variants,
+    };
+
+fn process_pinyin_tones(s: &str, setting: TextProcessorSetting) -> String {
+    match setting {
+        TextProcessorSetting::BiDirectional(opt) => match opt {
+            BidirectionalPreProcessorOptions::Off => s.to_string(),
+            BidirectionalPreProcessorOptions::Direct => pinyin_numbers_to_marks(s),
+            BidirectionalPreProcessorOptions::Inverse => pinyin_marks_to_numbers(s),
+        },
+        _ => s.to_string(),
+    }
+}
+
+pub const CONVERT_PINYIN_TONES: BidirectionalConversionPreProcessor =
+    BidirectionalConversionPreProcessor {
+        name: "Convert Pinyin Tone Numbers to Tone Marks",
+        description: "ni3 hao3 → nǐ hǎo and vice versa",
+        options: &[
+            TextProcessorSetting::BiDirectional(BidirectionalPreProcessorOptions::Off),
+            TextProcessorSetting::BiDirectional(BidirectionalPreProcessorOptions::Direct),
+            TextProcessorSetting::BiDirectional(BidirectionalPreProcessorOptions::Inverse),
+        ],
+        process: process_pinyin_tones,
+    };
+
+fn remove_pinyin_tones_helper(text: &str, setting: TextProcessorSetting) -> String {
+    if matches!(setting, TextProcessorSetting::Bool(true)) {
+        return pinyin_to_toneless(text);
+    }
+    text.to_owned()
+}
+
+pub const REMOVE_PINYIN_TONES: TextProcessor = TextProcessor {
+    name: "Remove Pinyin Tones",
+    description: "nǐ hǎo / ni3 hao3 → ni hao",
+    options: BASIC_TEXT_PROCESSOR_OPTIONS,
+    process: remove_pinyin_tones_helper,
+};
+
+fn remove_arabic_diacritics_helper(text: &str, setting: TextProcessorSetting) -> String {
+    if matches!(setting, TextProcessorSetting::Bool(true)) {
+        return remove_arabic_script_diacritics(text);
+    }
+    text.to_owned()
+}
+
+pub const REMOVE_ARABIC_DIACRITICS: TextProcessor = TextProcessor {
+    name: "Remove Arabic Diacritics (Tashkeel)",
+    description: "كَتَبَ → كتب",
+    options: BASIC_TEXT_PROCESSOR_OPTIONS,
+    process: remove_arabic_diacritics_helper,
+};
+
+fn remove_tatweel_helper(text: &str, setting: TextProcessorSetting) -> String {
+    if matches!(setting, TextProcessorSetting::Bool(true)) {
+        return remove_tatweel(text);
+    }
+    text.to_owned()
+}
+
+pub const REMOVE_TATWEEL: TextProcessor = TextProcessor {
+    name: "Remove Tatweel Characters",
+    description: "كـــتاب → كتاب",
+    options: BASIC_TEXT_PROCESSOR_OPTIONS,
+    process: remove_tatweel_helper,
+};
+
+fn normalize_alef_variants_helper(text: &str, setting: TextProcessorSetting) -> String {
+    if matches!(setting, TextProcessorSetting::Bool(true)) {
+        return normalize_alef_variants(text);
+    }
+    text.to_owned()
+}
+
+pub const NORMALIZE_ALEF_VARIANTS: TextProcessor = TextProcessor {
+    name: "Normalize Alef Variants",
+    description: "أ إ آ ٱ → ا",
+    options: BASIC_TEXT_PROCESSOR_OPTIONS,
+    process: normalize_alef_variants_helper,
+};
+
+fn add_hamza_top_helper(text: &str, setting: TextProcessorSetting) -> String {
+    if matches!(setting, TextProcessorSetting::Bool(true)) {
+        return add_hamza_top(text);
+    }
+    text.to_owned()
+}
+
+pub const ADD_HAMZA_TOP: TextProcessor = TextProcessor {
+    name: "Add Hamza to top of Initial Alef",
+    description: "اكبر → أكبر",
+    options: BASIC_TEXT_PROCESSOR_OPTIONS,
+    process: add_hamza_top_helper,
+};
+
+fn add_hamza_bottom_helper(text: &str, setting: TextProcessorSetting) -> String {
+    if matches!(setting, TextProcessorSetting::Bool(true)) {
+        return add_hamza_bottom(text);
+    }
+    text.to_owned()
+}
+
+pub const ADD_HAMZA_BOTTOM: TextProcessor = TextProcessor {
+    name: "Add Hamza to bottom of Initial Alef",
+    description: "اسلام → إسلام",
+    options: BASIC_TEXT_PROCESSOR_OPTIONS,
+    process: add_hamza_bottom_helper,
+};
+
+fn convert_alef_maksura_to_yeh_helper(text: &str, setting: TextProcessorSetting) -> String {
+    if matches!(setting, TextProcessorSetting::Bool(true)) {
+        return convert_alef_maksura_to_yeh(text);
+    }
+    text.to_owned()
+}
+
+pub const CONVERT_ALEF_MAKSURA_TO_YEH: TextProcessor = TextProcessor {
+    name: "Convert Alef Maksura to Yeh",
+    description: "على → علي",
+    options: BASIC_TEXT_PROCESSOR_OPTIONS,
+    process: convert_alef_maksura_to_yeh_helper,
+};
+
+fn process_persian_characters(s: &str, setting: TextProcessorSetting) -> String {
+    match setting {
+        TextProcessorSetting::BiDirectional(opt) => match opt {
+            BidirectionalPreProcessorOptions::Off => s.to_string(),
+            BidirectionalPreProcessorOptions::Direct => convert_arabic_to_persian_characters(s),
+            BidirectionalPreProcessorOptions::Inverse => convert_persian_to_arabic_characters(s),
+        },
+        _ => s.to_string(),
+    }
+}
+
+pub const CONVERT_PERSIAN_CHARACTERS: BidirectionalConversionPreProcessor =
+    BidirectionalConversionPreProcessor {
+        name: "Convert Arabic Yeh & Kaf to Persian",
+        description: "ي ك → ی ک and vice versa",
+        options: &[
+            TextProcessorSetting::BiDirectional(BidirectionalPreProcessorOptions::Off),
+            TextProcessorSetting::BiDirectional(BidirectionalPreProcessorOptions::Direct),
+            TextProcessorSetting::BiDirectional(BidirectionalPreProcessorOptions::Inverse),
+        ],
+        process: process_persian_characters,
+    };
+
+fn remove_zero_width_non_joiner_helper(text: &str, setting: TextProcessorSetting) -> String {
+    if matches!(setting, TextProcessorSetting::Bool(true)) {
+        return remove_zero_width_non_joiner(text);
+    }
+    text.to_owned()
+}
+
+pub const REMOVE_ZERO_WIDTH_NON_JOINER: TextProcessor = TextProcessor {
+    name: "Remove Zero-Width Non-Joiners",
+    description: "می‌روم → میروم",
+    options: BASIC_TEXT_PROCESSOR_OPTIONS,
+    process: remove_zero_width_non_joiner_helper,
+};
+
+fn remove_russian_diacritics_helper(text: &str, setting: TextProcessorSetting) -> String {
+    if matches!(setting, TextProcessorSetting::Bool(true)) {
+        return remove_russian_diacritics(text);
+    }
+    text.to_owned()
+}
+
+pub const REMOVE_RUSSIAN_DIACRITICS: TextProcessor = TextProcessor {
+    name: "Remove Stress Marks and Convert Ё to Е",
+    description: "ёлка́ → елка",
+    options: BASIC_TEXT_PROCESSOR_OPTIONS,
+    process: remove_russian_diacritics_helper,
+};
+
+fn disassemble_hangul_helper(text: &str, setting: TextProcessorSetting) -> String {
+    if matches!(setting, TextProcessorSetting::Bool(true)) {
+        return disassemble_hangul(text);
+    }
+    text.to_owned()
+}
+
+pub const DISASSEMBLE_HANGUL: TextProcessor = TextProcessor {
+    name: "Disassemble Hangul",
+    description: "먹어요 → ㅁㅓㄱㅇㅓㅇㅛ",
+    options: BASIC_TEXT_PROCESSOR_OPTIONS,
+    process: disassemble_hangul_helper,
+};
+
+fn reassemble_hangul_helper(text: &str, setting: TextProcessorSetting) -> String {
+    if matches!(setting, TextProcessorSetting::Bool(true)) {
+        return reassemble_hangul(text);
+    }
+    text.to_owned()
+}
+
+pub const REASSEMBLE_HANGUL: TextProcessor = TextProcessor {
+    name: "Reassemble Hangul",
+    description: "ㅁㅓㄱㄷㅏ → 먹다",
+    options: BASIC_TEXT_PROCESSOR_OPTIONS,
+    process: reassemble_hangul_helper,
+};
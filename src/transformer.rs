@@ -2,6 +2,7 @@ use std::{
     clone,
     fmt::Display,
     sync::{Arc, LazyLock},
+    time::Instant,
 };
 
 use derivative::Derivative;
@@ -10,13 +11,14 @@ use fancy_regex::Regex;
 use indexmap::IndexMap;
 use serde::{Deserialize, Deserializer, Serialize};
 use snafu::ResultExt;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 use crate::{
     descriptors::{JapanesePreProcessors, LanguageDescriptor, PreAndPostProcessors},
     en::en_transforms::{PARTICLES_DISJUNCTION, PHRASAL_VERB_WORD_DISJUNCTION},
     ja::ja_transforms::JAPANESE_TRANSFORMS_DESCRIPTOR,
     ja::japanese::is_string_partially_japanese,
+    rule_index::RuleIndex,
     text_preprocessors::{
         ALPHABETIC_TO_HIRAGANA, ALPHANUMERIC_WIDTH_VARIANTS, COLLAPSE_EMPHATIC_SEQUENCES,
         CONVERT_HALF_WIDTH_CHARACTERS, CONVERT_HIRAGANA_TO_KATAKANA,
@@ -30,6 +32,9 @@ pub struct InternalTransform {
     pub rules: Vec<InternalRule>,
     pub heuristic: Regex,
     pub description: Option<&'static str>,
+    /// Translations of `name` & `description`, see [`Transform::i18n`].
+    pub i18n: Vec<TransformI18n>,
+    pub weight: f64,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -45,8 +50,37 @@ pub struct InternalRule {
     pub inflected_str: Option<String>,
     pub deinflected: &'static str,
     pub deinflect_fn: DeinflectFnType,
-    pub conditions_in: usize,
-    pub conditions_out: usize,
+    pub conditions_in: ConditionFlags,
+    pub conditions_out: ConditionFlags,
+}
+
+impl InternalRule {
+    /// The reverse of [`RuleDeinflectFnTrait::deinflect`].
+    ///
+    /// Only literal `Suffix`, `Prefix` and `WholeWord` rules can be reversed;
+    /// `None` is returned for anything else, or if `text` doesn't end/start with
+    /// (or equal) the rule's deinflected string.
+    /// A candidate is only returned if it deinflects back to `text`.
+    pub fn inflect(&self, text: &str) -> Option<String> {
+        let inflected = self.inflected_str.as_deref()?;
+        let deinflected = self.deinflected;
+        let candidate = match self.deinflect_fn {
+            DeinflectFnType::GenericSuffix => {
+                format!("{}{inflected}", text.strip_suffix(deinflected)?)
+            }
+            DeinflectFnType::GenericPrefix => {
+                format!("{inflected}{}", text.strip_prefix(deinflected)?)
+            }
+            DeinflectFnType::GenericWholeWord if text == deinflected => inflected.to_string(),
+            _ => return None,
+        };
+        if !self.is_inflected.is_match(&candidate).unwrap_or(false)
+            || self.deinflect(&candidate) != text
+        {
+            return None;
+        }
+        Some(candidate)
+    }
 }
 
 impl RuleDeinflectFnTrait for InternalRule {
@@ -75,12 +109,12 @@ impl RuleDeinflectFnTrait for InternalRule {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TransformedText {
     pub text: String,
-    pub conditions: usize,
+    pub conditions: ConditionFlags,
     pub trace: Trace,
 }
 
 impl TransformedText {
-    pub fn create_transformed_text(text: String, conditions: usize, trace: Trace) -> Self {
+    pub fn create_transformed_text(text: String, conditions: ConditionFlags, trace: Trace) -> Self {
         Self {
             text,
             conditions,
@@ -91,6 +125,274 @@ impl TransformedText {
 
 pub type Trace = Vec<TraceFrame>;
 
+/// Limits for [`LanguageTransformer::transform_with_options`].
+///
+/// `None` means unlimited, which is what [`LanguageTransformer::transform`] uses.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransformOptions {
+    /// Maximum number of rules applied in a single chain (ie: `trace.len()`).
+    pub max_depth: Option<usize>,
+    /// Maximum number of candidates returned, including the source text itself
+    /// (`Some(0)` returns nothing).
+    pub max_candidates: Option<usize>,
+    /// Stop expanding candidates once this instant has passed.
+    pub deadline: Option<Instant>,
+}
+
+/// Output of [`LanguageTransformer::transform_with_options`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransformReport {
+    pub results: Vec<TransformedText>,
+    /// `true` if a [`TransformOptions`] limit stopped the search before every candidate
+    /// was found.
+    pub truncated: bool,
+    /// Problems found in the transform rules while searching, in the order they were hit.
+    pub warnings: Vec<TransformWarning>,
+}
+
+impl TransformReport {
+    /// Merges results with the same `text` & `conditions` into a single
+    /// [`MergedTransformedText`], keeping the order of each text's first occurrence.
+    pub fn merge_duplicates(self) -> Vec<MergedTransformedText> {
+        merge_duplicates(self.results)
+    }
+}
+
+/// Every way [`LanguageTransformer::transform`] reached the same `text` with the same
+/// `conditions`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MergedTransformedText {
+    pub text: String,
+    pub conditions: ConditionFlags,
+    /// One trace per rule path, in the order `transform` found them (shortest first).
+    pub traces: Vec<Trace>,
+}
+
+/// See [`TransformReport::merge_duplicates`].
+pub fn merge_duplicates(
+    results: impl IntoIterator<Item = TransformedText>,
+) -> Vec<MergedTransformedText> {
+    let mut merged: IndexMap<(String, ConditionFlags), Vec<Trace>> = IndexMap::new();
+    for TransformedText {
+        text,
+        conditions,
+        trace,
+    } in results
+    {
+        merged.entry((text, conditions)).or_default().push(trace);
+    }
+    merged
+        .into_iter()
+        .map(|((text, conditions), traces)| MergedTransformedText {
+            text,
+            conditions,
+            traces,
+        })
+        .collect()
+}
+
+/// Output of [`LanguageTransformer::transform_candidates`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandidateReport {
+    pub candidates: CandidateArena,
+    /// See [`TransformReport::truncated`].
+    pub truncated: bool,
+    pub warnings: Vec<TransformWarning>,
+}
+
+impl From<CandidateReport> for TransformReport {
+    fn from(report: CandidateReport) -> Self {
+        Self {
+            results: report.candidates.transformed_texts().collect(),
+            truncated: report.truncated,
+            warnings: report.warnings,
+        }
+    }
+}
+
+/// How a [`Candidate`] was derived from its parent candidate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CandidateFrame {
+    /// Index of the parent in the [`CandidateArena`].
+    pub parent: usize,
+    pub transform: &'static str,
+    pub rule_index: usize,
+}
+
+/// A [`TransformedText`] without its trace, see [`CandidateArena`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Candidate {
+    pub text: String,
+    pub conditions: ConditionFlags,
+    /// Number of rules applied to the source text to get here (ie: `trace.len()`).
+    pub depth: usize,
+    /// `None` for the source text.
+    pub frame: Option<CandidateFrame>,
+}
+
+/// Every candidate of a transform, in breadth-first order,
+/// each pointing back at the candidate it was deinflected from.
+///
+/// Intermediate texts are stored once, [`Trace`]s are only built on demand
+/// by [`CandidateArena::trace`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CandidateArena {
+    candidates: Vec<Candidate>,
+}
+
+impl CandidateArena {
+    pub(crate) fn new(source_text: &str) -> Self {
+        Self {
+            candidates: vec![Candidate {
+                text: source_text.to_string(),
+                conditions: 0,
+                depth: 0,
+                frame: None,
+            }],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.candidates.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.candidates.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Candidate> {
+        self.candidates.get(index)
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Candidate> {
+        self.candidates.iter()
+    }
+
+    /// The frames leading to `index`, innermost (closest to the dictionary form) first,
+    /// same as [`Trace`].
+    pub fn frames(&self, index: usize) -> impl Iterator<Item = CandidateFrame> + '_ {
+        std::iter::successors(self.candidates[index].frame, |frame| {
+            self.candidates[frame.parent].frame
+        })
+    }
+
+    pub fn trace(&self, index: usize) -> Trace {
+        self.frames(index)
+            .map(|frame| TraceFrame {
+                text: self.candidates[frame.parent].text.clone(),
+                transform: frame.transform.to_string(),
+                rule_index: frame.rule_index,
+            })
+            .collect()
+    }
+
+    pub fn transformed_text(&self, index: usize) -> TransformedText {
+        let candidate = &self.candidates[index];
+        TransformedText::create_transformed_text(
+            candidate.text.clone(),
+            candidate.conditions,
+            self.trace(index),
+        )
+    }
+
+    pub fn transformed_texts(&self) -> impl Iterator<Item = TransformedText> + '_ {
+        (0..self.len()).map(|index| self.transformed_text(index))
+    }
+
+    /// `true` if `transform`'s rule `rule_index` was already applied to `index`'s text
+    /// earlier in its chain.
+    fn has_cycle(&self, index: usize, transform: &str, rule_index: usize) -> bool {
+        let text = &self.candidates[index].text;
+        self.frames(index).any(|frame| {
+            frame.transform == transform
+                && frame.rule_index == rule_index
+                && self.candidates[frame.parent].text == *text
+        })
+    }
+}
+
+/// Iterator returned by [`LanguageTransformer::transform_iter`].
+#[derive(Debug, Clone)]
+pub struct TransformIter<'a> {
+    transformer: Option<&'a LanguageTransformer>,
+    candidates: CandidateArena,
+    /// Index of the next candidate to yield,
+    /// every candidate before the last yielded one has been expanded.
+    next_index: usize,
+    warnings: Vec<TransformWarning>,
+}
+
+impl<'a> TransformIter<'a> {
+    /// Without a `transformer`, only `source_text` itself is yielded.
+    pub(crate) fn new(transformer: Option<&'a LanguageTransformer>, source_text: &str) -> Self {
+        Self {
+            transformer,
+            candidates: CandidateArena::new(source_text),
+            next_index: 0,
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Warnings for the candidates expanded so far.
+    pub fn warnings(&self) -> &[TransformWarning] {
+        &self.warnings
+    }
+
+    /// The candidates found so far.
+    pub fn candidates(&self) -> &CandidateArena {
+        &self.candidates
+    }
+}
+
+impl Iterator for TransformIter<'_> {
+    type Item = TransformedText;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let (Some(transformer), Some(last)) = (self.transformer, self.next_index.checked_sub(1))
+        {
+            transformer.deinflect_candidate(&mut self.candidates, last, &mut self.warnings);
+        }
+        if self.next_index >= self.candidates.len() {
+            return None;
+        }
+        self.next_index += 1;
+        Some(self.candidates.transformed_text(self.next_index - 1))
+    }
+}
+
+/// A non-fatal problem [`LanguageTransformer::transform_with_options`] ran into.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, thiserror::Error)]
+pub enum TransformWarning {
+    /// Applying the rule would repeat a step already in `trace`, so it was skipped.
+    #[error("[cycle detected]\n  transform: [{transform_id}] rule[{rule_index}]\n  text: {text}\n  trace: {trace:?}")]
+    CycleDetected {
+        transform_id: String,
+        rule_index: usize,
+        /// The text the rule was about to be applied to.
+        text: String,
+        trace: Trace,
+    },
+}
+
+/// A set of conditions, one bit per condition type of a [`LanguageTransformer`].
+///
+/// Sub-conditions (e.g. `v1` = `v1d | v1p`) are the union of their children's bits,
+/// so up to `ConditionFlags::BITS` (128) leaf conditions can be registered.
+pub type ConditionFlags = u128;
+
+/// A single row of [`LanguageTransformer::conjugation_table`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConjugationTableEntry {
+    /// The inflected form.
+    pub text: String,
+    /// Conditions of the inflected form.
+    pub conditions: ConditionFlags,
+    /// The trace [`LanguageTransformer::transform`] produces for `text`.
+    pub trace: Trace,
+    /// The name & description of every transform in `trace`, in the same order.
+    pub inflection_rules: InflectionRuleChain,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct TraceFrame {
     pub text: String,
@@ -98,7 +400,7 @@ pub struct TraceFrame {
     pub rule_index: usize,
 }
 
-pub type ConditionTypeToConditionFlagsMap = HashMap<String, u32>;
+pub type ConditionTypeToConditionFlagsMap = HashMap<String, ConditionFlags>;
 
 pub struct LanguageTransformDescriptorInternal {
     transforms: Vec<InternalTransform>,
@@ -140,15 +442,16 @@ pub enum LanguageTransformerError {
     },
     #[snafu(display("Failed to get conditions_flag_map: {e}"))]
     ConditionsFlagMap { e: String },
-    #[snafu(display(
-        "[cycle detected]\n  transform: [{transform_name}] rule[{j}]\n  text: {text}\n  trace: {trace:?}"
-    ))]
-    CycleDetected {
-        transform_name: String,
-        j: usize,
-        text: String,
-        trace: Vec<TraceFrame>,
-    },
+    #[snafu(display("transform does not exist: {transform_id}"))]
+    MissingTransform { transform_id: String },
+    #[snafu(display("rule does not exist: {transform_id}.rules[{index}]"))]
+    MissingRule { transform_id: String, index: usize },
+    #[snafu(display("condition does not exist: {condition}"))]
+    MissingCondition { condition: String },
+    #[snafu(display("condition is not a part of speech: {condition}"))]
+    NotPartOfSpeech { condition: String },
+    #[snafu(display("no transformer registered for language: {language}"))]
+    MissingLanguage { language: String },
 }
 
 #[derive(thiserror::Error)]
@@ -169,13 +472,137 @@ impl std::fmt::Debug for ConditionError {
     }
 }
 
+/// Weights for [`LanguageTransformer::rank`].
+///
+/// A candidate's score is
+/// `dictionary_form` (if its conditions include a dictionary form part of speech)
+/// `+ Σ rule_specificity * inflected_len - trace_length * transform.weight`
+/// over every frame of its trace.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RankingOptions {
+    /// Cost of every rule applied, multiplied by the transform's [`Transform::weight`].
+    pub trace_length: f64,
+    /// Bonus per character of a rule's `inflected_str`,
+    /// so `-させられた` beats a chain of shorter suffixes matching the same text.
+    pub rule_specificity: f64,
+    /// Bonus for candidates whose conditions match a dictionary form part of speech.
+    pub dictionary_form: f64,
+}
+
+impl Default for RankingOptions {
+    fn default() -> Self {
+        Self {
+            trace_length: 1.0,
+            rule_specificity: 0.5,
+            dictionary_form: 2.0,
+        }
+    }
+}
+
+/// A [`TransformedText`] and its [`LanguageTransformer::score`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RankedTransformedText {
+    pub transformed_text: TransformedText,
+    pub score: f64,
+}
+
+/// A [`TraceFrame`] resolved against its transform & rule, see [`LanguageTransformer::explain`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExplainedFrame {
+    /// The text the rule was applied to.
+    pub inflected_text: String,
+    /// The text the rule produced.
+    pub deinflected_text: String,
+    pub transform_id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub rule_index: usize,
+    pub rule_type: RuleType,
+    /// The rule's inflected pattern, without the regex anchor (ie: `させる`).
+    pub inflected: String,
+    pub deinflected: String,
+    /// Conditions the rule requires of `inflected_text`, ie: `[("-た", "-た form ending")]`.
+    /// Empty if the rule accepts anything.
+    pub conditions_before: Vec<ConditionName>,
+    /// Conditions `deinflected_text` has after the rule, ie: `[("v1", "Ichidan verb")]`.
+    pub conditions_after: Vec<ConditionName>,
+}
+
+/// Output of [`LanguageTransformer::explain`].
+///
+/// Displays as `食べさせられた ←(-た) 食べさせられる ←(potential or passive) 食べさせる ←(causative) 食べる`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Explanation {
+    /// The text `transform` was given.
+    pub source_text: String,
+    /// The deinflected text.
+    pub text: String,
+    /// Condition types of `text`.
+    pub conditions: Vec<String>,
+    /// In the order the rules were applied to `source_text`,
+    /// ie: the reverse of [`Trace`].
+    pub frames: Vec<ExplainedFrame>,
+}
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.source_text)?;
+        for frame in &self.frames {
+            write!(f, " ←({}) {}", frame.name, frame.deinflected_text)?;
+        }
+        Ok(())
+    }
+}
+
+/// A condition type & its [`Condition::name`],
+/// see [`LanguageTransformer::condition_names_from_flags`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConditionName {
+    /// ie: `v1`
+    pub condition_type: String,
+    /// ie: `Ichidan verb`
+    pub name: String,
+}
+
+/// A dictionary [`LanguageTransformer::transform_with_lookup`] checks candidates against.
+pub trait TermLookup {
+    type Entry: TermEntry;
+    /// Every entry for `term`, empty if `term` isn't in the dictionary.
+    fn lookup(&self, term: &str) -> Vec<Self::Entry>;
+}
+
+/// A single dictionary entry returned by [`TermLookup::lookup`].
+pub trait TermEntry {
+    /// The entry's parts of speech, ie: `["v1", "vt"]`.
+    fn parts_of_speech(&self) -> &[String];
+}
+
+/// The entry is the term's parts of speech.
+impl TermEntry for Vec<String> {
+    fn parts_of_speech(&self) -> &[String] {
+        self
+    }
+}
+
+/// Maps a term to the parts of speech of each of its entries.
+impl TermLookup for HashMap<String, Vec<Vec<String>>> {
+    type Entry = Vec<String>;
+    fn lookup(&self, term: &str) -> Vec<Self::Entry> {
+        self.get(term).cloned().unwrap_or_default()
+    }
+}
+
 /// [`MultiLanguageTransformer`]'s inner language specific deconjugator.
 #[derive(Debug, Clone, Default)]
 pub struct LanguageTransformer {
     next_flag_index: usize,
     transforms: Vec<InternalTransform>,
-    condition_type_to_condition_flags_map: IndexMap<String, usize>,
-    part_of_speech_to_condition_flags_map: IndexMap<String, usize>,
+    condition_type_to_condition_flags_map: IndexMap<String, ConditionFlags>,
+    part_of_speech_to_condition_flags_map: IndexMap<String, ConditionFlags>,
+    /// Every registered condition, keyed by condition type.
+    conditions: IndexMap<String, Condition>,
+    /// See [`LanguageTransformer::build_rule_index`].
+    rule_index: Option<RuleIndex>,
 }
 
 impl LanguageTransformer {
@@ -183,11 +610,22 @@ impl LanguageTransformer {
         Self::default()
     }
 
+    /// Makes [`LanguageTransformer::transform`] find applicable rules with a [`RuleIndex`]
+    /// instead of testing every transform's regexes.
+    ///
+    /// The index is kept up to date by later [`LanguageTransformer::add_descriptor`] calls,
+    /// and `transform`'s output is identical with or without it.
+    pub fn build_rule_index(&mut self) {
+        self.rule_index = Some(RuleIndex::new(&self.transforms));
+    }
+
     fn clear(&mut self) {
         self.next_flag_index = 0;
         self.transforms.clear();
         self.condition_type_to_condition_flags_map.clear();
         self.part_of_speech_to_condition_flags_map.clear();
+        self.conditions.clear();
+        self.rule_index = None;
     }
 
     /// Add a language transform descriptor to the transformer.
@@ -212,7 +650,8 @@ impl LanguageTransformer {
                 name,
                 description,
                 rules,
-                ..
+                weight,
+                i18n,
             } = transform;
             let mut rules2: Vec<InternalRule> = Vec::with_capacity(rules.len());
             for (j, rule) in rules.iter().enumerate() {
@@ -274,10 +713,15 @@ impl LanguageTransformer {
                 description: *description,
                 rules: rules2,
                 heuristic,
+                i18n: i18n.clone().unwrap_or_default(),
+                weight: weight.unwrap_or(1.0),
             });
         }
         self.next_flag_index = condition_flags_map.next_flag_index;
         self.transforms.extend(transforms2);
+        if self.rule_index.is_some() {
+            self.build_rule_index();
+        }
         for ConditionMapEntry(condition_type, condition) in &condition_entries {
             if let Some(flags) = condition_flags_map.map.get(condition_type.as_str()) {
                 self.condition_type_to_condition_flags_map
@@ -286,6 +730,8 @@ impl LanguageTransformer {
                     self.part_of_speech_to_condition_flags_map
                         .insert(condition_type.to_string(), *flags);
                 }
+                self.conditions
+                    .insert(condition_type.to_string(), condition.clone());
             }
         }
         Ok(())
@@ -294,21 +740,21 @@ impl LanguageTransformer {
     pub(crate) fn get_condition_flags_from_parts_of_speech(
         &self,
         parts_of_speech: &[impl AsRef<str>],
-    ) -> usize {
+    ) -> ConditionFlags {
         self.get_condition_flags(&self.part_of_speech_to_condition_flags_map, parts_of_speech)
     }
 
     pub(crate) fn get_condition_flags_from_condition_types(
         &self,
         condition_types: &[impl AsRef<str>],
-    ) -> usize {
+    ) -> ConditionFlags {
         self.get_condition_flags(&self.condition_type_to_condition_flags_map, condition_types)
     }
 
     pub(crate) fn get_condition_flags_from_single_condition_type<T: AsRef<str>>(
         &self,
         condition_type: T,
-    ) -> usize {
+    ) -> ConditionFlags {
         self.get_condition_flags(
             &self.condition_type_to_condition_flags_map,
             &[condition_type.as_ref()],
@@ -318,70 +764,489 @@ impl LanguageTransformer {
     // Excerpt from: impl LanguageTransformer
     /// https://github.com/yomidevs/yomitan/blob/c3bec65bc44a33b1b1686e5d81a6910e42889174/ext/js/language/language-transformer.js#L120C11-L120C11
     pub(crate) fn transform(&self, source_text: impl AsRef<str>) -> Vec<TransformedText> {
-        let source_text = source_text.as_ref();
-        let mut results = vec![TransformedText::create_transformed_text(
-            source_text.to_string(),
-            0,
-            Vec::new(),
-        )];
+        self.transform_with_options(source_text, &TransformOptions::default())
+            .results
+    }
+
+    /// [`LanguageTransformer::transform`], but stops expanding candidates once one of
+    /// the [`TransformOptions`] limits is hit.
+    ///
+    /// Candidates are still produced in the same breadth-first order,
+    /// so a truncated result is always a prefix of the unbounded one
+    /// (minus any candidates deeper than `max_depth`).
+    pub fn transform_with_options(
+        &self,
+        source_text: impl AsRef<str>,
+        options: &TransformOptions,
+    ) -> TransformReport {
+        self.transform_candidates(source_text, options).into()
+    }
+
+    /// [`LanguageTransformer::transform_with_options`], but returns the candidates as a
+    /// [`CandidateArena`] instead of building a [`Trace`] for every one of them.
+    pub fn transform_candidates(
+        &self,
+        source_text: impl AsRef<str>,
+        options: &TransformOptions,
+    ) -> CandidateReport {
+        let mut candidates = CandidateArena::new(source_text.as_ref());
+        let mut truncated = false;
+        let mut warnings = Vec::new();
 
         let mut i = 0;
-        while i < results.len() {
-            let (text, conditions, trace) = {
-                let entry = &results[i];
-                (entry.text.clone(), entry.conditions, entry.trace.clone())
-            };
+        while i < candidates.len() {
+            // checked before every expansion, so the source text itself counts too
+            if let Some(max_candidates) = options.max_candidates {
+                if candidates.len() > max_candidates {
+                    candidates.candidates.truncate(max_candidates);
+                    truncated = true;
+                    break;
+                }
+            }
+            if options
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+            {
+                truncated = true;
+                break;
+            }
 
-            for transform in &self.transforms {
-                if !transform.heuristic.is_match(&text).unwrap() {
-                    continue;
+            if options
+                .max_depth
+                .is_some_and(|max_depth| candidates.candidates[i].depth >= max_depth)
+            {
+                // not expanded, so cycles past the limit aren't reported
+                truncated |= self
+                    .matching_rules(&candidates.candidates[i])
+                    .into_iter()
+                    .any(|(t, j)| !candidates.has_cycle(i, self.transforms[t].id, j));
+                i += 1;
+                continue;
+            }
+
+            self.deinflect_candidate(&mut candidates, i, &mut warnings);
+            i += 1;
+        }
+
+        CandidateReport {
+            candidates,
+            truncated,
+            warnings,
+        }
+    }
+
+    /// [`LanguageTransformer::transform`], keeping only the candidates `dictionary` has an
+    /// entry for whose parts of speech match the candidate's conditions,
+    /// paired with every such entry.
+    ///
+    /// This is the filtering step of yomitan's `Translator._getDeinflections`.
+    pub fn transform_with_lookup<L: TermLookup>(
+        &self,
+        source_text: impl AsRef<str>,
+        dictionary: &L,
+    ) -> Vec<(TransformedText, L::Entry)> {
+        let mut matches = Vec::new();
+        for candidate in self.transform_iter(source_text) {
+            for entry in dictionary.lookup(&candidate.text) {
+                let flags = self.get_condition_flags_from_parts_of_speech(entry.parts_of_speech());
+                if Self::conditions_match(candidate.conditions, flags) {
+                    matches.push((candidate.clone(), entry));
                 }
+            }
+        }
+        matches
+    }
 
-                let transform_id = transform.id;
-                for (j, rule) in transform.rules.iter().enumerate() {
-                    if !Self::conditions_match(conditions, rule.conditions_in)
-                        || !rule.is_inflected.is_match(&text).unwrap()
-                    {
-                        continue;
-                    }
+    /// Scores `candidate` with `options`, higher is more plausible.
+    ///
+    /// Frames referring to transforms or rules this transformer doesn't have are ignored.
+    pub fn score(&self, candidate: &TransformedText, options: &RankingOptions) -> f64 {
+        let all_parts_of_speech = self
+            .part_of_speech_to_condition_flags_map
+            .values()
+            .fold(0, |flags, f| flags | f);
+        let mut score = 0.0;
+        // the source text (conditions `0`) could be anything, so it gets no bonus
+        if candidate.conditions & all_parts_of_speech != 0 {
+            score += options.dictionary_form;
+        }
+        for frame in &candidate.trace {
+            let Some(transform) = self.transforms.iter().find(|t| t.id == frame.transform) else {
+                continue;
+            };
+            score -= options.trace_length * transform.weight;
+            let inflected_len = transform
+                .rules
+                .get(frame.rule_index)
+                .and_then(|rule| rule.inflected_str.as_deref())
+                .map_or(0, |inflected| inflected.chars().count());
+            score += options.rule_specificity * inflected_len as f64;
+        }
+        score
+    }
 
-                    // Cycle detection
-                    if trace.iter().any(|frame| {
-                        frame.transform == transform_id
-                            && frame.rule_index == j
-                            && frame.text == text
-                    }) {
-                        let e = LanguageTransformerError::CycleDetected {
-                            transform_name: transform.name.into(),
-                            j,
-                            text: text.clone(),
-                            trace: trace.clone(),
-                        };
-                        println!("[error]: {e}");
+    /// Sorts `candidates` by [`LanguageTransformer::score`], highest first.
+    ///
+    /// Candidates with equal scores keep their original (breadth-first) order.
+    pub fn rank(
+        &self,
+        candidates: impl IntoIterator<Item = TransformedText>,
+        options: &RankingOptions,
+    ) -> Vec<RankedTransformedText> {
+        let mut ranked: Vec<RankedTransformedText> = candidates
+            .into_iter()
+            .map(|transformed_text| RankedTransformedText {
+                score: self.score(&transformed_text, options),
+                transformed_text,
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.score.total_cmp(&a.score));
+        ranked
+    }
+
+    /// Resolves every frame of `transformed_text`'s trace to its transform, rule
+    /// and condition names, for showing why `transformed_text` was produced.
+    ///
+    /// Fails if the trace refers to a transform or rule this transformer doesn't have.
+    pub fn explain(
+        &self,
+        transformed_text: &TransformedText,
+    ) -> Result<Explanation, LanguageTransformerError> {
+        let mut frames = Vec::with_capacity(transformed_text.trace.len());
+        let mut deinflected_text = &transformed_text.text;
+        // the trace is innermost first, so walk it from the dictionary form outwards
+        for frame in &transformed_text.trace {
+            let Some(transform) = self.transforms.iter().find(|t| t.id == frame.transform) else {
+                return Err(LanguageTransformerError::MissingTransform {
+                    transform_id: frame.transform.clone(),
+                });
+            };
+            let Some(rule) = transform.rules.get(frame.rule_index) else {
+                return Err(LanguageTransformerError::MissingRule {
+                    transform_id: frame.transform.clone(),
+                    index: frame.rule_index,
+                });
+            };
+            frames.push(ExplainedFrame {
+                inflected_text: frame.text.clone(),
+                deinflected_text: deinflected_text.clone(),
+                transform_id: transform.id.to_string(),
+                name: transform.name.to_string(),
+                description: transform.description.map(str::to_string),
+                rule_index: frame.rule_index,
+                rule_type: rule.rule_type,
+                inflected: rule.inflected().to_string(),
+                deinflected: rule.deinflected.to_string(),
+                conditions_before: self.condition_names_from_flags(rule.conditions_in, None),
+                conditions_after: self.condition_names_from_flags(rule.conditions_out, None),
+            });
+            deinflected_text = &frame.text;
+        }
+        frames.reverse();
+        Ok(Explanation {
+            source_text: deinflected_text.clone(),
+            text: transformed_text.text.clone(),
+            conditions: self.get_condition_types(transformed_text.conditions),
+            frames,
+        })
+    }
+
+    /// The reverse of [`LanguageTransformer::get_condition_flags_from_condition_types`].
+    ///
+    /// Only the broadest condition types covered by `flags` are returned,
+    /// ie: `v1` instead of `v1`, `v1d` and `v1p`.
+    /// Of condition types with identical flags (ie: `v` with its only sub-condition `v_phr`),
+    /// only the first registered is returned.
+    pub fn get_condition_types(&self, flags: ConditionFlags) -> Vec<String> {
+        let covered: Vec<(&String, ConditionFlags)> = self
+            .condition_type_to_condition_flags_map
+            .iter()
+            .filter(|(_, &f)| f != 0 && f & flags == f)
+            .map(|(condition_type, &f)| (condition_type, f))
+            .collect();
+        covered
+            .iter()
+            .enumerate()
+            .filter(|(i, (_, f))| {
+                !covered.iter().enumerate().any(|(j, (_, other))| {
+                    (other != f && other & f == *f) || (other == f && j < *i)
+                })
+            })
+            .map(|(_, (condition_type, _))| condition_type.to_string())
+            .collect()
+    }
+
+    /// [`LanguageTransformer::get_condition_types`] paired with each condition's name,
+    /// ie: `3` -> `[("v1", "Ichidan verb")]`.
+    ///
+    /// With a `display_locale` (ie: `"ja"`), the condition's [`RuleI18n`] name for that
+    /// locale is used if it has one (`[("v1", "一段動詞")]`).
+    pub fn condition_names_from_flags(
+        &self,
+        flags: ConditionFlags,
+        display_locale: Option<&str>,
+    ) -> Vec<ConditionName> {
+        self.get_condition_types(flags)
+            .into_iter()
+            .map(|condition_type| ConditionName {
+                name: self.conditions.get(&condition_type).map_or(
+                    condition_type.clone(),
+                    |condition| {
+                        let i18n = display_locale.and_then(|locale| {
+                            condition
+                                .i18n
+                                .iter()
+                                .flatten()
+                                .find(|i18n| i18n.language == locale)
+                        });
+                        i18n.map_or(condition.name, |i18n| i18n.name).to_string()
+                    },
+                ),
+                condition_type,
+            })
+            .collect()
+    }
+
+    /// Lazily yields the same candidates as [`LanguageTransformer::transform`],
+    /// in the same order.
+    ///
+    /// A candidate's deinflections are only computed once the candidate after it is requested,
+    /// so stopping early (ie: at the first dictionary hit) skips the rest of the expansion.
+    pub fn transform_iter(&self, source_text: impl AsRef<str>) -> TransformIter<'_> {
+        TransformIter::new(Some(self), source_text.as_ref())
+    }
+
+    /// Pushes every deinflection of `candidates[index]` one rule deeper into `candidates`.
+    fn deinflect_candidate(
+        &self,
+        candidates: &mut CandidateArena,
+        index: usize,
+        warnings: &mut Vec<TransformWarning>,
+    ) {
+        for (t, j) in self.matching_rules(&candidates.candidates[index]) {
+            self.apply_rule(&self.transforms[t], j, candidates, index, warnings);
+        }
+    }
+
+    /// `(transform index, rule index)` of every rule that deinflects `candidate`.
+    fn matching_rules(&self, candidate: &Candidate) -> Vec<(usize, usize)> {
+        let Candidate {
+            text, conditions, ..
+        } = candidate;
+        let conditions = *conditions;
+        let mut matches = Vec::new();
+        match &self.rule_index {
+            Some(rule_index) => {
+                for (t, j) in rule_index.matching_rules(text, &self.transforms) {
+                    let rule = &self.transforms[t].rules[j];
+                    if Self::conditions_match(conditions, rule.conditions_in) {
+                        matches.push((t, j));
+                    }
+                }
+            }
+            None => {
+                for (t, transform) in self.transforms.iter().enumerate() {
+                    if !transform.heuristic.is_match(text).unwrap() {
                         continue;
                     }
+                    for (j, rule) in transform.rules.iter().enumerate() {
+                        if Self::conditions_match(conditions, rule.conditions_in)
+                            && rule.is_inflected.is_match(text).unwrap()
+                        {
+                            matches.push((t, j));
+                        }
+                    }
+                }
+            }
+        }
+        matches
+    }
 
-                    let new_text = rule.deinflect(&text);
-                    // --- TEMPORARY DEBUGGING ---
-                    // --- END DEBUGGING ---
-                    let new_frame = TraceFrame {
-                        transform: transform_id.into(),
-                        rule_index: j,
-                        text: text.clone(),
-                    };
-                    let new_trace = self.extend_trace(trace.clone(), new_frame);
-                    results.push(TransformedText::create_transformed_text(
-                        new_text.to_owned(),
-                        rule.conditions_out,
-                        new_trace,
-                    ));
+    /// Deinflects `candidates[index]` with `transform.rules[j]` and pushes the result,
+    /// unless the same rule was already applied to the same text earlier in its chain,
+    /// in which case a [`TransformWarning::CycleDetected`] is pushed instead.
+    fn apply_rule(
+        &self,
+        transform: &InternalTransform,
+        j: usize,
+        candidates: &mut CandidateArena,
+        index: usize,
+        warnings: &mut Vec<TransformWarning>,
+    ) {
+        let transform_id = transform.id;
+        let rule = &transform.rules[j];
+        let text = &candidates.candidates[index].text;
+        // Cycle detection
+        if candidates.has_cycle(index, transform_id, j) {
+            warnings.push(TransformWarning::CycleDetected {
+                transform_id: transform_id.to_string(),
+                rule_index: j,
+                text: text.clone(),
+                trace: candidates.trace(index),
+            });
+            return;
+        }
+
+        let new_candidate = Candidate {
+            text: rule.deinflect(text),
+            conditions: rule.conditions_out,
+            depth: candidates.candidates[index].depth + 1,
+            frame: Some(CandidateFrame {
+                parent: index,
+                transform: transform_id,
+                rule_index: j,
+            }),
+        };
+        candidates.candidates.push(new_candidate);
+    }
+
+    /// Runs the transform rules in reverse.
+    ///
+    /// Starting from `dictionary_form` (with the `condition_type` part of speech, ie: `v5`),
+    /// applies each transform in `transform_ids` in order and returns every surface form
+    /// the rules can produce.
+    ///
+    /// Each result's `trace` is the same trace [`LanguageTransformer::transform`] produces
+    /// when deinflecting the result's `text` back to `dictionary_form`,
+    /// and `conditions` are the conditions of the inflected form.
+    ///
+    /// Only `Suffix`, `Prefix` and `WholeWord` rules are reversible,
+    /// see [`InternalRule::inflect`].
+    pub fn inflect(
+        &self,
+        dictionary_form: impl AsRef<str>,
+        condition_type: impl AsRef<str>,
+        transform_ids: &[impl AsRef<str>],
+    ) -> Result<Vec<TransformedText>, LanguageTransformerError> {
+        let condition_type = condition_type.as_ref();
+        let Some(&conditions) = self
+            .condition_type_to_condition_flags_map
+            .get(condition_type)
+        else {
+            return Err(LanguageTransformerError::MissingCondition {
+                condition: condition_type.to_string(),
+            });
+        };
+        let mut results = vec![TransformedText::create_transformed_text(
+            dictionary_form.as_ref().to_string(),
+            conditions,
+            Vec::new(),
+        )];
+
+        for transform_id in transform_ids {
+            let transform_id = transform_id.as_ref();
+            let transforms: Vec<&InternalTransform> = self
+                .transforms
+                .iter()
+                .filter(|transform| transform.id == transform_id)
+                .collect();
+            if transforms.is_empty() {
+                return Err(LanguageTransformerError::MissingTransform {
+                    transform_id: transform_id.to_string(),
+                });
+            }
+
+            let mut next_results = Vec::new();
+            for current in &results {
+                for transform in &transforms {
+                    Self::inflect_with_transform(current, transform, &mut next_results);
                 }
             }
+            results = next_results;
+        }
 
-            i += 1;
+        Ok(results)
+    }
+
+    /// Applies every rule of `transform` to `current` in reverse, pushing the results.
+    fn inflect_with_transform(
+        current: &TransformedText,
+        transform: &InternalTransform,
+        results: &mut Vec<TransformedText>,
+    ) {
+        for (j, rule) in transform.rules.iter().enumerate() {
+            // deinflecting the new form must leave conditions
+            // that are compatible with the current form
+            if !Self::conditions_match(rule.conditions_out, current.conditions) {
+                continue;
+            }
+            let Some(new_text) = rule.inflect(&current.text) else {
+                continue;
+            };
+            let mut new_trace = current.trace.clone();
+            new_trace.push(TraceFrame {
+                text: new_text.clone(),
+                transform: transform.id.to_string(),
+                rule_index: j,
+            });
+            results.push(TransformedText::create_transformed_text(
+                new_text,
+                rule.conditions_in,
+                new_trace,
+            ));
         }
+    }
 
-        results
+    /// Lists every single-step inflection of `dictionary_form`, followed by the forms
+    /// produced by each chain in `chains` (transform ids in the order they're applied,
+    /// see [`LanguageTransformer::inflect`]).
+    ///
+    /// `part_of_speech` must be a dictionary form condition (ie: `v1`, not `-ます`).
+    /// A surface form is listed once per transform chain producing it,
+    /// so the same text can appear under several labels (ie: `hablamos`).
+    /// Single-step entries are ordered by transform order in the descriptor.
+    pub fn conjugation_table(
+        &self,
+        dictionary_form: impl AsRef<str>,
+        part_of_speech: impl AsRef<str>,
+        chains: &[&[&str]],
+    ) -> Result<Vec<ConjugationTableEntry>, LanguageTransformerError> {
+        let dictionary_form = dictionary_form.as_ref();
+        let part_of_speech = part_of_speech.as_ref();
+        if !self
+            .part_of_speech_to_condition_flags_map
+            .contains_key(part_of_speech)
+        {
+            let condition = part_of_speech.to_string();
+            if self
+                .condition_type_to_condition_flags_map
+                .contains_key(part_of_speech)
+            {
+                return Err(LanguageTransformerError::NotPartOfSpeech { condition });
+            }
+            return Err(LanguageTransformerError::MissingCondition { condition });
+        }
+
+        let mut forms: Vec<TransformedText> = Vec::new();
+        for transform in &self.transforms {
+            forms.extend(self.inflect(dictionary_form, part_of_speech, &[transform.id])?);
+        }
+        for chain in chains {
+            forms.extend(self.inflect(dictionary_form, part_of_speech, chain)?);
+        }
+        let mut seen: HashSet<(String, Vec<String>)> = HashSet::new();
+        forms.retain(|form| {
+            let transform_ids = form.trace.iter().map(|frame| frame.transform.clone());
+            seen.insert((form.text.clone(), transform_ids.collect()))
+        });
+
+        let table = forms
+            .into_iter()
+            .map(|form| {
+                let transform_ids: Vec<String> = form
+                    .trace
+                    .iter()
+                    .map(|frame| frame.transform.clone())
+                    .collect();
+                ConjugationTableEntry {
+                    inflection_rules: self.get_user_facing_inflection_rules(&transform_ids, None),
+                    text: form.text,
+                    conditions: form.conditions,
+                    trace: form.trace,
+                }
+            })
+            .collect();
+        Ok(table)
     }
 
     pub(crate) fn extend_trace(&self, trace: Trace, new_frame: TraceFrame) -> Trace {
@@ -392,9 +1257,15 @@ impl LanguageTransformer {
         new_trace
     }
 
+    /// The name & description of each transform in `inflection_rules`.
+    ///
+    /// With a `display_locale` (ie: `"ja"`), the transform's [`TransformI18n`] for that
+    /// locale is used, falling back to the default name & description for anything
+    /// it doesn't translate. Unknown transforms keep their id as their name.
     pub fn get_user_facing_inflection_rules(
         &self,
         inflection_rules: &[String],
+        display_locale: Option<&str>,
     ) -> InflectionRuleChain {
         inflection_rules
             .iter()
@@ -404,13 +1275,15 @@ impl LanguageTransformer {
                     .iter()
                     .find(|transform| transform.id == *rule);
                 if let Some(full_rule) = full_rule {
+                    let i18n = display_locale.and_then(|locale| {
+                        full_rule.i18n.iter().find(|i18n| i18n.language == locale)
+                    });
                     return InflectionRule {
-                        name: full_rule.name.to_string(),
-                        description: full_rule
-                            .description
-                            .into_iter()
-                            .map(|str| Some(str.to_string()))
-                            .collect(),
+                        name: i18n.map_or(full_rule.name, |i18n| i18n.name).to_string(),
+                        description: i18n
+                            .and_then(|i18n| i18n.description)
+                            .or(full_rule.description)
+                            .map(str::to_string),
                     };
                 }
                 InflectionRule {
@@ -423,7 +1296,10 @@ impl LanguageTransformer {
 
     /// If `currentConditions` is `0`, then `nextConditions` is ignored and `true` is returned.
     /// Otherwise, there must be at least one shared condition between `currentConditions` and `nextConditions`.
-    pub fn conditions_match(current_conditions: usize, next_conditions: usize) -> bool {
+    pub fn conditions_match(
+        current_conditions: ConditionFlags,
+        next_conditions: ConditionFlags,
+    ) -> bool {
         current_conditions == 0 || (current_conditions & next_conditions) != 0
     }
 
@@ -432,7 +1308,7 @@ impl LanguageTransformer {
         conditions: Vec<ConditionMapEntry>,
         next_flag_index: usize,
     ) -> Result<ConditionFlagsMap, ConditionError> {
-        const MAX_FLAG_LIMIT: usize = 32;
+        const MAX_FLAG_LIMIT: usize = ConditionFlags::BITS as usize;
         let mut next_flag_index = next_flag_index;
         let mut condition_flags_map = IndexMap::with_capacity(conditions.len());
         let mut targets = conditions;
@@ -442,8 +1318,7 @@ impl LanguageTransformer {
             for target in targets {
                 let ConditionMapEntry(condition_type, condition) = target.clone();
                 let sub_conditions = condition.sub_conditions;
-                let mut flags = 0;
-                match sub_conditions {
+                let flags = match sub_conditions {
                     Some(sub_conditions) => {
                         let Ok(multi_flags) = LanguageTransformer::get_condition_flags_strict(
                             &condition_flags_map,
@@ -452,16 +1327,17 @@ impl LanguageTransformer {
                             next_targets.push(target);
                             continue;
                         };
-                        flags = multi_flags
+                        multi_flags
                     }
                     None => {
                         if next_flag_index >= MAX_FLAG_LIMIT {
                             return Err(ConditionError::MaxConditions);
                         }
-                        flags = 1 << next_flag_index;
+                        let flags = 1 << next_flag_index;
                         next_flag_index += 1;
+                        flags
                     }
-                }
+                };
                 condition_flags_map.insert(condition_type, flags);
             }
             if next_targets.len() == targets_len {
@@ -483,11 +1359,11 @@ impl LanguageTransformer {
     }
 
     /// Converts a Rule's condition flags into a single condition
-    /// &\[&str\] -> usize (for InternalRule's conditions)
+    /// &\[&str\] -> [`ConditionFlags`] (for InternalRule's conditions)
     pub fn get_condition_flags_strict<'a>(
-        condition_flags_map: &IndexMap<String, usize>,
+        condition_flags_map: &IndexMap<String, ConditionFlags>,
         condition_types: &'a [&'a str],
-    ) -> Result<usize, ConditionError> {
+    ) -> Result<ConditionFlags, ConditionError> {
         let mut flags = 0;
 
         for (index, cond_type) in condition_types.iter().enumerate() {
@@ -505,9 +1381,9 @@ impl LanguageTransformer {
 
     fn get_condition_flags(
         &self,
-        condition_flags_map: &IndexMap<String, usize>,
+        condition_flags_map: &IndexMap<String, ConditionFlags>,
         condition_types: &[impl AsRef<str>],
-    ) -> usize {
+    ) -> ConditionFlags {
         let mut flags = 0;
         for condition_type in condition_types {
             let flags2 = condition_flags_map
@@ -553,7 +1429,7 @@ impl LanguageTransformDescriptor {
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ConditionFlagsMap {
-    pub map: IndexMap<String, usize>,
+    pub map: IndexMap<String, ConditionFlags>,
     pub next_flag_index: usize,
 }
 
@@ -601,6 +1477,9 @@ pub struct Transform {
     pub description: Option<&'static str>,
     pub i18n: Option<Vec<TransformI18n>>,
     pub rules: Vec<Rule>,
+    /// How costly applying this transform is when ranking candidates,
+    /// `None` is `1.0`. See [`RankingOptions`].
+    pub weight: Option<f64>,
 }
 
 #[derive(Debug, Clone)]
@@ -663,7 +1542,7 @@ pub trait RuleDeinflectFnTrait: 'static {
             DeinflectFnType::EnPhrasalVerbInterposedObjectRule => {
                 Self::english_create_phrasal_verb_interposed_object_rule(text)
             }
-                // Destructure the enum to get the 'replacement' value
+            // Destructure the enum to get the 'replacement' value
             DeinflectFnType::Pronominal => {
                 let regex_to_use = self.is_inflected_regex();
 
@@ -672,7 +1551,7 @@ pub trait RuleDeinflectFnTrait: 'static {
                 // after a successful `is_match` check (in transform())
                 let captures = regex_to_use.captures(text).unwrap().unwrap();
 
-                // group 0 is the full match, 
+                // group 0 is the full match,
                 // 1 is the pronoun, 2 is the stem, 3 is the ending.
                 let verb_stem = captures.get(2).unwrap().as_str();
                 let verb_ending = captures.get(3).unwrap().as_str();
@@ -680,9 +1559,12 @@ pub trait RuleDeinflectFnTrait: 'static {
             }
 
             // Destructure to get all the stem-change parameters
-            DeinflectFnType::GenericStemChange { stem_from, stem_to, ending_re, ending_to } => {
-                self.deinflect_generic_stem_change(text, stem_from, stem_to, ending_re, ending_to)
-            }
+            DeinflectFnType::GenericStemChange {
+                stem_from,
+                stem_to,
+                ending_re,
+                ending_to,
+            } => self.deinflect_generic_stem_change(text, stem_from, stem_to, ending_re, ending_to),
 
             // Destructure to get all the special-cased stem-change parameters
             DeinflectFnType::SpecialCasedStemChange {
@@ -692,23 +1574,17 @@ pub trait RuleDeinflectFnTrait: 'static {
                 default_stem_from,
                 default_stem_to,
                 ending_re,
-                ending_to
-            } => {
-                self.deinflect_special_cased_stem_change(
-                    text,
-                    prefix,
-                    special_stem_from,
-                    special_stem_to,
-                    default_stem_from,
-                    default_stem_to,
-                    ending_re,
-                    ending_to
-                )
-            }
-                _ => panic!(
-                    "failed to call `.deinflect(${text})` because deinflect function has not been implemented yet for: {}",
-                    self.deinflect_fn_type()
-                ),
+                ending_to,
+            } => self.deinflect_special_cased_stem_change(
+                text,
+                prefix,
+                special_stem_from,
+                special_stem_to,
+                default_stem_from,
+                default_stem_to,
+                ending_re,
+                ending_to,
+            ),
         }
     }
 
@@ -736,6 +1612,7 @@ pub trait RuleDeinflectFnTrait: 'static {
 
     /// Deinflects a stem-changing verb that has a special case,
     /// like "jugar" (u->ue) or "oler" (o->hue).
+    #[allow(clippy::too_many_arguments)]
     fn deinflect_special_cased_stem_change(
         &self,
         text: &str,
@@ -792,11 +1669,21 @@ pub trait RuleDeinflectFnTrait: 'static {
     //     format!("{base}{deinflected_suffix}")
     // }
 
+    /// Mirrors yomitan's `suffixInflection`:
+    /// `text.slice(0, -inflectedSuffix.length) + deinflectedSuffix`
     fn deinflect_generic_suffix(&self, text: &str) -> String {
-        let regex = self.is_inflected_regex();
         let deinflected_suffix = self.deinflected();
-        // Use the regex to replace the matched suffix. This is guaranteed to be correct.
-        regex.replace(text, deinflected_suffix).to_string()
+        let Some(inflected_suffix) = self.inflected_str() else {
+            // no literal suffix to slice off, fall back to the regex
+            let regex = self.is_inflected_regex();
+            return regex.replace(text, deinflected_suffix).to_string();
+        };
+        let suffix_len = inflected_suffix.chars().count();
+        let base_end = match suffix_len {
+            0 => text.len(),
+            n => text.char_indices().rev().nth(n - 1).map_or(0, |(i, _)| i),
+        };
+        format!("{}{deinflected_suffix}", &text[..base_end])
     }
 
     fn deinflect_generic_prefix(&self, text: &str) -> String {
@@ -898,25 +1785,40 @@ impl PartialEq for SuffixRule {
 
 /// Custom deserialization function for javascript Regex.
 ///
-/// Note: If `RegExp.prototype.toJSON()` isn't used to serialize the regex,
-/// it will default to an object: `{}`.
+/// Returns the pattern source (what `RegExp.prototype.source` returns in JS).
+/// Accepts a plain pattern string (`"qux$"`), a regex literal (`"/qux$/gi"`),
+/// `{"source": "qux$", "flags": "gi"}`, or the `RegExp.prototype.toJSON()` format:
 ///> {"rgx":"/qux$/gi","date":"2014-03-21T23:11:33.749Z"}"
-fn deserialize_regex<'de, D>(deserializer: D) -> Result<Regex, D::Error>
+///
+/// Note: If `RegExp.prototype.toJSON()` isn't used to serialize the regex,
+/// it will default to an object: `{}`, which is an error.
+pub(crate) fn deserialize_regex<'de, D>(deserializer: D) -> Result<String, D::Error>
 where
     D: Deserializer<'de>,
 {
-    let s: serde_json::Value = Deserialize::deserialize(deserializer)?;
-    if let serde_json::Value::Object(obj) = s {
-        if let Some(re_val) = obj.get("rgx") {
-            if let serde_json::Value::String(re_str) = re_val {
-                return Regex::new(re_str).map_err(serde::de::Error::custom);
-            }
-            unreachable!();
+    fn literal_source(literal: &str) -> &str {
+        match literal.strip_prefix('/').and_then(|s| s.rsplit_once('/')) {
+            Some((source, _flags)) => source,
+            None => literal,
         }
-        let def = Regex::new(r"").unwrap();
-        return Ok(def);
     }
-    panic!("'isInflected': was expected to be a regex object, found {s:?}");
+
+    let s: serde_json::Value = Deserialize::deserialize(deserializer)?;
+    let source = match &s {
+        serde_json::Value::String(re_str) => Some(literal_source(re_str)),
+        serde_json::Value::Object(obj) => match (obj.get("rgx"), obj.get("source")) {
+            (Some(serde_json::Value::String(re_str)), _) => Some(literal_source(re_str)),
+            (_, Some(serde_json::Value::String(source))) => Some(source.as_str()),
+            _ => None,
+        },
+        _ => None,
+    };
+    match source {
+        Some(source) => Ok(source.to_string()),
+        None => Err(serde::de::Error::custom(format!(
+            "'isInflected': was expected to be a serialized regex, found {s} (was `RegExp.prototype.toJSON` set?)"
+        ))),
+    }
 }
 
 #[cfg(test)]
@@ -1026,14 +1928,297 @@ mod language_transformer_tests {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn rule_index_same_output() {
+        #[rustfmt::skip]
+        let texts = ["食べさせられませんでした", "読んでいない", "来させる", "unwalked", "tried", "went", "looking up", "hablándose", "dímelo", "pusieron", "x"];
+        for descriptor in crate::languages::get_all_language_transform_descriptors() {
+            let mut lt = LanguageTransformer::new();
+            lt.add_descriptor(&descriptor.language_transforms).unwrap();
+            let mut indexed = lt.clone();
+            indexed.build_rule_index();
+            for text in texts {
+                assert_eq!(lt.transform(text), indexed.transform(text), "{text}");
+            }
+        }
+    }
+
+    #[test]
+    fn cycle_warning() {
+        let json = r#"{ "language": "xx", "conditions": {}, "transforms": {
+            "noop": { "name": "noop", "rules": [
+                { "type": "suffix", "isInflected": "a$", "deinflected": "a" }
+            ] } } }"#;
+        let lt = LanguageTransformer::from_yomitan_json(json).unwrap();
+        let report = lt.transform_with_options("xa", &TransformOptions::default());
+        assert_eq!(report.results.len(), 2);
+        assert!(!report.truncated);
+        assert_eq!(
+            report.warnings,
+            [TransformWarning::CycleDetected {
+                transform_id: "noop".into(),
+                rule_index: 0,
+                text: "xa".into(),
+                trace: report.results[1].trace.clone(),
+            }]
+        );
+
+        // the cycle is one rule past `max_depth`, so it's never expanded
+        let options = TransformOptions {
+            max_depth: Some(1),
+            ..Default::default()
+        };
+        let report = lt.transform_with_options("xa", &options);
+        assert_eq!(report.results.len(), 2);
+        assert!(!report.truncated);
+        assert!(report.warnings.is_empty());
+    }
+
+    #[test]
+    fn candidate_arena() {
+        let mut lt = LanguageTransformer::new();
+        lt.add_descriptor(&JAPANESE_TRANSFORMS_DESCRIPTOR).unwrap();
+        let report = lt.transform_candidates("食べさせられた", &TransformOptions::default());
+        let candidates = &report.candidates;
+        let results = lt.transform("食べさせられた");
+        assert_eq!(candidates.len(), results.len());
+        for (index, (candidate, result)) in candidates.iter().zip(&results).enumerate() {
+            assert_eq!(candidate.text, result.text);
+            assert_eq!(candidate.depth, result.trace.len());
+            assert_eq!(candidates.trace(index), result.trace);
+        }
+        let taberu = candidates.iter().position(|c| c.text == "食べる").unwrap();
+        let transforms: Vec<&str> = candidates.frames(taberu).map(|f| f.transform).collect();
+        assert_eq!(transforms, ["causative", "potential or passive", "-た"]);
+    }
+
+    #[test]
+    fn merge_duplicates() {
+        let mut lt = LanguageTransformer::new();
+        lt.add_descriptor(&JAPANESE_TRANSFORMS_DESCRIPTOR).unwrap();
+        let report = lt.transform_with_options("愛しくありません", &TransformOptions::default());
+        let results = report.results.clone();
+        let merged = report.merge_duplicates();
+        let arimasu: Vec<_> = merged
+            .iter()
+            .filter(|m| m.text == "愛しくあります")
+            .collect();
+        assert_eq!(arimasu.len(), 2);
+        // same text, different conditions: both are kept
+        assert!(arimasu.iter().all(|m| m.traces.len() == 1));
+        assert_eq!(
+            merged.iter().map(|m| m.traces.len()).sum::<usize>(),
+            results.len()
+        );
+        for m in &merged {
+            let expected: Vec<&Trace> = results
+                .iter()
+                .filter(|tt| tt.text == m.text && tt.conditions == m.conditions)
+                .map(|tt| &tt.trace)
+                .collect();
+            assert_eq!(m.traces.iter().collect::<Vec<_>>(), expected);
+        }
+
+        let mut lt = LanguageTransformer::new();
+        lt.add_descriptor(&crate::en::en_transforms::ENGLISH_TRANSFORMS_DESCRIPTOR)
+            .unwrap();
+        let merged = lt
+            .transform_with_options("unhappiest", &TransformOptions::default())
+            .merge_duplicates();
+        let happ = merged
+            .iter()
+            .find(|m| m.text == "happ" && m.traces.len() > 1)
+            .unwrap();
+        let chains: Vec<Vec<&str>> = happ
+            .traces
+            .iter()
+            .map(|trace| trace.iter().map(|f| f.transform.as_str()).collect())
+            .collect();
+        assert_eq!(
+            chains,
+            [["-y", "un-", "superlative"], ["-y", "superlative", "un-"]]
+        );
+    }
+
+    #[test]
+    fn rank_weights() {
+        let mut file = crate::descriptor_file::LanguageTransformDescriptorFile::from(
+            &*JAPANESE_TRANSFORMS_DESCRIPTOR,
+        );
+        let mut lt = LanguageTransformer::new();
+        lt.add_descriptor(&file.clone().into_descriptor().unwrap())
+            .unwrap();
+        let options = RankingOptions::default();
+        let ranked = lt.rank(lt.transform("食べさせられた"), &options);
+        let taberu = ranked
+            .iter()
+            .find(|r| r.transformed_text.text == "食べる")
+            .unwrap();
+        assert_eq!(taberu.score, lt.score(&taberu.transformed_text, &options));
+        // 2.0 (v1) + 0.5 * (3 + 3 + 1) - 3 * 1.0
+        assert_eq!(taberu.score, 2.5);
+
+        file.transforms.get_mut("causative").unwrap().weight = Some(3.0);
+        let mut lt = LanguageTransformer::new();
+        lt.add_descriptor(&file.into_descriptor().unwrap()).unwrap();
+        let reranked = lt.rank(lt.transform("食べさせられた"), &options);
+        let taberu = reranked
+            .iter()
+            .find(|r| r.transformed_text.text == "食べる")
+            .unwrap();
+        assert_eq!(taberu.score, 0.5);
+        assert_ne!(reranked[0].transformed_text.text, "食べる");
+    }
+
+    #[test]
+    fn explain() {
+        let mut lt = LanguageTransformer::new();
+        lt.add_descriptor(&JAPANESE_TRANSFORMS_DESCRIPTOR).unwrap();
+        let taberu = lt
+            .transform("食べさせられた")
+            .into_iter()
+            .find(|tt| tt.text == "食べる")
+            .unwrap();
+        let explanation = lt.explain(&taberu).unwrap();
+        assert_eq!(
+            explanation.to_string(),
+            "食べさせられた ←(-た) 食べさせられる ←(potential or passive) 食べさせる ←(causative) 食べる"
+        );
+        assert_eq!(explanation.conditions, ["v1"]);
+        let causative = &explanation.frames[2];
+        assert_eq!(causative.inflected_text, "食べさせる");
+        assert_eq!(causative.deinflected_text, "食べる");
+        assert_eq!(causative.rule_type, RuleType::Suffix);
+        assert_eq!(
+            (causative.inflected.as_str(), causative.deinflected.as_str()),
+            ("させる", "る")
+        );
+        let ichidan = [ConditionName {
+            condition_type: "v1".into(),
+            name: "Ichidan verb".into(),
+        }];
+        assert_eq!(causative.conditions_before, ichidan);
+        assert_eq!(causative.conditions_after, ichidan);
+        assert_eq!(
+            explanation.frames[0].conditions_before,
+            [ConditionName {
+                condition_type: "-た".into(),
+                name: "-た form ending".into(),
+            }]
+        );
+
+        let source = lt.transform("食べた").swap_remove(0);
+        assert_eq!(lt.explain(&source).unwrap().to_string(), "食べた");
+
+        let mut bogus = taberu;
+        bogus.trace[0].rule_index = usize::MAX;
+        assert!(matches!(
+            lt.explain(&bogus),
+            Err(LanguageTransformerError::MissingRule { .. })
+        ));
+    }
+
+    #[test]
+    fn user_facing_inflection_rules_i18n() {
+        let mut lt = LanguageTransformer::new();
+        lt.add_descriptor(&JAPANESE_TRANSFORMS_DESCRIPTOR).unwrap();
+        let rules = ["-ゃ", "potential or passive", "unknown"].map(String::from);
+        let default = lt.get_user_facing_inflection_rules(&rules, None);
+        assert_eq!(default[1].name, "potential or passive");
+        assert_eq!(
+            lt.get_user_facing_inflection_rules(&rules, Some("xx")),
+            default
+        );
+
+        let ja = lt.get_user_facing_inflection_rules(&rules, Some("ja"));
+        assert_eq!(
+            ja[0],
+            InflectionRule {
+                name: "～ゃ".into(),
+                description: Some("「～ば」の短縮".into()),
+            }
+        );
+        // no translated description, so the default one is kept
+        assert_eq!(ja[1].name, "～られる");
+        assert_eq!(ja[1].description, default[1].description);
+        assert_eq!(ja[2], default[2]);
+    }
+
+    #[test]
+    fn condition_names_from_flags() {
+        let mut lt = LanguageTransformer::new();
+        lt.add_descriptor(&JAPANESE_TRANSFORMS_DESCRIPTOR).unwrap();
+        let v1 = lt.get_condition_flags_from_condition_types(&["v1d", "v1p"]);
+        let names = lt.condition_names_from_flags(v1, None);
+        assert_eq!(
+            names,
+            [ConditionName {
+                condition_type: "v1".into(),
+                name: "Ichidan verb".into(),
+            }]
+        );
+        let flags = lt.get_condition_flags_from_condition_types(&["v1", "v5d", "adj-i"]);
+        let types: Vec<String> = lt
+            .condition_names_from_flags(flags, None)
+            .into_iter()
+            .map(|c| c.condition_type)
+            .collect();
+        assert_eq!(types, ["v1", "v5d", "adj-i"]);
+        assert!(lt.condition_names_from_flags(0, None).is_empty());
+
+        let ja = lt.condition_names_from_flags(flags, Some("ja"));
+        assert_eq!(ja[0].name, "一段動詞");
+        assert_eq!(ja[2].name, "形容詞");
+        let te = lt.get_condition_flags_from_condition_types(&["-て"]);
+        // no `ja` translation, so the default name is kept
+        assert_eq!(
+            lt.condition_names_from_flags(te, Some("ja"))[0].name,
+            "Intermediate -て endings for progressive or perfect tense"
+        );
+    }
+
+    #[test]
+    fn condition_flags_limit() {
+        let condition = |sub_conditions| Condition {
+            name: "",
+            is_dictionary_form: true,
+            i18n: None,
+            sub_conditions,
+        };
+        let leaf_types: Vec<String> = (0..ConditionFlags::BITS).map(|i| format!("c{i}")).collect();
+        let mut conditions: Vec<ConditionMapEntry> = leaf_types
+            .iter()
+            .map(|ct| ConditionMapEntry(ct.clone(), condition(None)))
+            .collect();
+        let last_two: &'static [&'static str] = &["c126", "c127"];
+        conditions.push(ConditionMapEntry("last".into(), condition(Some(last_two))));
+        let lt = LanguageTransformer::new();
+        let flags_map = lt.get_condition_flags_map(conditions.clone(), 0).unwrap();
+        assert_eq!(flags_map.map["c40"], 1 << 40);
+        assert_eq!(flags_map.map["last"], 0b11 << 126);
+        assert!(LanguageTransformer::conditions_match(
+            flags_map.map["last"],
+            flags_map.map["c127"]
+        ));
+        assert!(!LanguageTransformer::conditions_match(
+            flags_map.map["last"],
+            flags_map.map["c40"]
+        ));
+        conditions.push(ConditionMapEntry("c128".into(), condition(None)));
+        assert!(matches!(
+            lt.get_condition_flags_map(conditions, 0),
+            Err(ConditionError::MaxConditions)
+        ));
+    }
+
     #[test]
     fn add_descriptor() {
         let mut lt = LanguageTransformer::new();
         lt.add_descriptor(&JAPANESE_TRANSFORMS_DESCRIPTOR).unwrap();
         #[rustfmt::skip]
-        let assert_postcfm: IndexMap<String, usize> = IndexMap::from_iter([("v1".into(), 3), ("v5".into(), 28), ("vk".into(), 32), ("vs".into(), 64), ("vz".into(), 128), ("adj-i".into(), 256)]);
+        let assert_postcfm: IndexMap<String, ConditionFlags> = IndexMap::from_iter([("v1".into(), 3), ("v5".into(), 28), ("vk".into(), 32), ("vs".into(), 64), ("vz".into(), 128), ("adj-i".into(), 256)]);
         #[rustfmt::skip]
-        let assert_cttcfm: IndexMap<String, usize> = IndexMap::from_iter([("v".into(), 255), ("v1".into(), 3), ("v1d".into(), 1), ("v1p".into(), 2), ("v5".into(), 28), ("v5d".into(), 4), ("v5s".into(), 24), ("v5ss".into(), 8), ("v5sp".into(), 16), ("vk".into(), 32), ("vs".into(), 64), ("vz".into(), 128), ("adj-i".into(), 256), ("-ます".into(), 512), ("-ません".into(), 1024), ("-て".into(), 2048), ("-ば".into(), 4096), ("-く".into(), 8192), ("-た".into(), 16384), ("-ん".into(), 32768), ("-なさい".into(), 65536), ("-ゃ".into(), 131072)]);
+        let assert_cttcfm: IndexMap<String, ConditionFlags> = IndexMap::from_iter([("v".into(), 255), ("v1".into(), 3), ("v1d".into(), 1), ("v1p".into(), 2), ("v5".into(), 28), ("v5d".into(), 4), ("v5s".into(), 24), ("v5ss".into(), 8), ("v5sp".into(), 16), ("vk".into(), 32), ("vs".into(), 64), ("vz".into(), 128), ("adj-i".into(), 256), ("-ます".into(), 512), ("-ません".into(), 1024), ("-て".into(), 2048), ("-ば".into(), 4096), ("-く".into(), 8192), ("-た".into(), 16384), ("-ん".into(), 32768), ("-なさい".into(), 65536), ("-ゃ".into(), 131072)]);
         assert_eq!(lt.next_flag_index, 18);
         assert_eq!(lt.transforms.len(), 53);
         assert_eq!(lt.part_of_speech_to_condition_flags_map, assert_postcfm);
@@ -1081,6 +2266,79 @@ mod language_transformer_tests {
         }
     }
 
+    #[test]
+    fn inflect() {
+        let mut lt = LanguageTransformer::new();
+        lt.add_descriptor(&JAPANESE_TRANSFORMS_DESCRIPTOR).unwrap();
+
+        let res = lt
+            .inflect(
+                "食べる",
+                "v1",
+                &["causative", "potential or passive", "-ます"],
+            )
+            .unwrap();
+        let texts: Vec<&str> = res.iter().map(|tt| tt.text.as_str()).collect();
+        assert_eq!(texts, ["食べさせられます"]);
+
+        // every inflected form deinflects back with the same trace
+        for inflected in &res {
+            let deinflected = lt.transform(&inflected.text);
+            assert!(deinflected
+                .iter()
+                .any(|tt| tt.text == "食べる" && tt.trace == inflected.trace));
+        }
+
+        let res = lt.inflect("読む", "v5", &["-ます"]).unwrap();
+        let texts: Vec<&str> = res.iter().map(|tt| tt.text.as_str()).collect();
+        assert_eq!(texts, ["読みます"]);
+
+        assert!(matches!(
+            lt.inflect("読む", "v5", &["nope"]),
+            Err(LanguageTransformerError::MissingTransform { .. })
+        ));
+        assert!(matches!(
+            lt.inflect("読む", "nope", &["-ます"]),
+            Err(LanguageTransformerError::MissingCondition { .. })
+        ));
+    }
+
+    #[test]
+    fn conjugation_table() {
+        let mut lt = LanguageTransformer::new();
+        lt.add_descriptor(&JAPANESE_TRANSFORMS_DESCRIPTOR).unwrap();
+
+        let single = lt.conjugation_table("食べる", "v1", &[]).unwrap();
+        assert!(single.iter().all(|entry| entry.trace.len() == 1));
+        let masu = single
+            .iter()
+            .find(|entry| entry.text == "食べます")
+            .unwrap();
+        assert_eq!(masu.inflection_rules[0].name, "-ます");
+        assert!(masu.inflection_rules[0].description.is_some());
+
+        let chains: &[&[&str]] = &[&["causative", "potential or passive"]];
+        let multi = lt.conjugation_table("食べる", "v1", chains).unwrap();
+        assert!(multi.len() > single.len());
+        assert!(multi.iter().any(|entry| entry.text == "食べさせられる"
+            && entry.trace.len() == 2
+            && entry.inflection_rules[0].name == "causative"));
+        // only the selected chains go beyond a single step
+        assert!(multi
+            .iter()
+            .filter(|entry| entry.trace.len() > 1)
+            .all(|entry| entry.inflection_rules[1].name == "potential or passive"));
+
+        assert!(matches!(
+            lt.conjugation_table("食べます", "-ます", &[]),
+            Err(LanguageTransformerError::NotPartOfSpeech { .. })
+        ));
+        assert!(matches!(
+            lt.conjugation_table("食べる", "v1", &[&["nope"]]),
+            Err(LanguageTransformerError::MissingTransform { .. })
+        ));
+    }
+
     #[test]
     fn get_condition_flags_map() {
         let assert_map = ConditionFlagsMap {
@@ -9,14 +9,38 @@ pub fn inflection(
     conditions_out: &'static [&'static str],
     rule_type: RuleType,
 ) -> Rule {
-    let regx = match rule_type {
+    let is_inflected = Regex::new(&inflection_pattern(inflected, rule_type)).unwrap();
+    compiled_inflection(
+        is_inflected,
+        inflected,
+        deinflected,
+        conditions_in,
+        conditions_out,
+        rule_type,
+    )
+}
+
+/// The `is_inflected` pattern [`inflection`] compiles for `inflected`.
+pub(crate) fn inflection_pattern(inflected: &str, rule_type: RuleType) -> String {
+    match rule_type {
         RuleType::Prefix => format!("^{inflected}"),
         RuleType::Suffix => format!("{inflected}$"),
         RuleType::WholeWord => format!("^{inflected}$"),
         _ => panic!(
             "{rule_type:?} is invalid, only RuleType Suffix, Prefix && WholeWord work with this fn"
         ),
-    };
+    }
+}
+
+/// [`inflection`] with its pattern already compiled, see [`inflection_pattern`].
+pub(crate) fn compiled_inflection(
+    is_inflected: Regex,
+    inflected: &str,
+    deinflected: &'static str,
+    conditions_in: &'static [&'static str],
+    conditions_out: &'static [&'static str],
+    rule_type: RuleType,
+) -> Rule {
     let deinflect_fn = match rule_type {
         RuleType::Suffix => DeinflectFnType::GenericSuffix,
         RuleType::Prefix => DeinflectFnType::GenericPrefix,
@@ -25,7 +49,6 @@ pub fn inflection(
             "{rule_type:?} is invalid, only RuleType Suffix, Prefix && WholeWord work with this fn"
         ),
     };
-    let is_inflected = Regex::new(&regx).unwrap();
     let deinflected = if deinflected.is_empty() {
         None
     } else {
@@ -83,6 +106,7 @@ pub fn generic_stem_change_rule(
 
 /// Creates a Rule for stem-changing verbs that have a special case (e.g., "jugar", "oler").
 /// These rules are always `RuleType::Other`.
+#[allow(clippy::too_many_arguments)]
 pub fn special_cased_stem_change_rule(
     // The stem used for the initial `is_inflected` check, e.g., "ue"
     inflected_stem: &'static str,
@@ -0,0 +1,130 @@
+use std::sync::LazyLock;
+
+use fancy_regex::Regex;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{
+    cjk_utils::{
+        is_code_point_in_ranges, CodepointRange, CJK_IDEOGRAPH_RANGES, CJK_PUNCTUATION_RANGE,
+        FULLWIDTH_CHARACTER_RANGES,
+    },
+    zh::chinese::{
+        IDEOGRAPHIC_SYMBOLS_AND_PUNCTUATION_RANGE, SMALL_FORM_RANGE, VERTICAL_FORM_RANGE,
+    },
+};
+
+/// Cantonese character ranges.
+///
+/// Same as [`crate::zh::chinese::CHINESE_RANGES`] without bopomofo,
+/// which is only used for Mandarin.
+/// Cantonese-only characters (ie: 𠵱, 𡃁) are mostly in the CJK extension blocks,
+/// which [`CJK_IDEOGRAPH_RANGES`] covers.
+pub static CANTONESE_RANGES: LazyLock<Vec<CodepointRange>> = LazyLock::new(|| {
+    CJK_IDEOGRAPH_RANGES
+        .into_iter()
+        .chain([CJK_PUNCTUATION_RANGE])
+        .chain(FULLWIDTH_CHARACTER_RANGES)
+        .chain([
+            IDEOGRAPHIC_SYMBOLS_AND_PUNCTUATION_RANGE,
+            SMALL_FORM_RANGE,
+            VERTICAL_FORM_RANGE,
+        ])
+        .collect()
+});
+
+/// Checks if a string contains at least one Cantonese character.
+pub fn is_string_partially_cantonese(s: &str) -> bool {
+    s.chars().any(|c| is_code_point_cantonese(c as u32))
+}
+
+/// Checks if a given Unicode code point is within [`CANTONESE_RANGES`].
+pub fn is_code_point_cantonese(code_point: u32) -> bool {
+    is_code_point_in_ranges(code_point, &CANTONESE_RANGES)
+}
+
+static JYUTPING_CLEANUP_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"[\s・·:'’-]").unwrap());
+
+/// Superscript tone numbers (ie: `nei⁵`) as ascii digits.
+fn normalize_tone_numbers(c: char) -> char {
+    match c {
+        '¹' => '1',
+        '²' => '2',
+        '³' => '3',
+        '⁴'..='⁶' => char::from_u32(c as u32 - '⁴' as u32 + '4' as u32).unwrap_or(c),
+        _ => c,
+    }
+}
+
+/// Normalizes a Jyutping reading: NFC, lowercased, superscript tone numbers as ascii digits,
+/// with whitespace and separators removed.
+///
+/// `Nei⁵ Hou²` -> `nei5hou2`
+pub fn normalize_jyutping(s: &str) -> String {
+    let normalized: String = s
+        .nfc()
+        .flat_map(char::to_lowercase)
+        .map(normalize_tone_numbers)
+        .collect();
+    JYUTPING_CLEANUP_REGEX
+        .replace_all(&normalized, "")
+        .into_owned()
+}
+
+/// [`normalize_jyutping`], ignoring tones: `nei5 hou2` -> `neihou`.
+pub fn normalize_jyutping_toneless(s: &str) -> String {
+    normalize_jyutping(s)
+        .chars()
+        .filter(|c| !matches!(c, '1'..='6'))
+        .collect()
+}
+
+#[cfg(test)]
+mod yue_tests {
+    use super::*;
+
+    #[test]
+    fn is_string_partially_cantonese_() {
+        assert!(is_string_partially_cantonese("佢哋"));
+        assert!(is_string_partially_cantonese("𠵱家"));
+        assert!(!is_string_partially_cantonese("ㄅㄆㄇ"));
+        assert!(!is_string_partially_cantonese("hello"));
+    }
+
+    #[test]
+    fn normalize_jyutping_() {
+        assert_eq!(normalize_jyutping("Nei5 Hou2"), "nei5hou2");
+        assert_eq!(normalize_jyutping("nei⁵ hou²"), "nei5hou2");
+        assert_eq!(normalize_jyutping("gwong2-dung1 'waa2"), "gwong2dung1waa2");
+        assert_eq!(normalize_jyutping_toneless("nei5 hou2"), "neihou");
+    }
+
+    #[test]
+    fn language_descriptor() {
+        use crate::languages::{
+            get_all_language_reading_normalizers, get_all_language_text_processors,
+            get_language_summaries, is_text_lookup_worthy,
+        };
+
+        let summary = get_language_summaries()
+            .into_iter()
+            .find(|s| s.iso == "yue")
+            .unwrap();
+        assert_eq!(summary.name, "Cantonese");
+        assert!(is_text_lookup_worthy("讀", "yue"));
+        assert!(!is_text_lookup_worthy("duk6", "yue"));
+        let normalizer = get_all_language_reading_normalizers()
+            .into_iter()
+            .find(|n| n.iso == "yue")
+            .unwrap();
+        assert_eq!((normalizer.reading_normalizer)("Duk6 Syu1"), "duk6syu1");
+        let processors = get_all_language_text_processors()
+            .into_iter()
+            .find(|p| p.iso == "yue")
+            .unwrap();
+        assert!(processors
+            .pre
+            .iter()
+            .any(|p| p.id == "convert_chinese_variants"));
+    }
+}
@@ -1,27 +1,15 @@
-// In a real project, you would add these to your Cargo.toml:
-// unicode-normalization = "0.1"
-// regex = "1"
-// once_cell = "1" // For lazy static Regex compilation
-
-use std::sync::LazyLock;
+use std::{collections::HashMap, sync::LazyLock};
 
 use fancy_regex::Regex;
-use unicode_normalization::{is_nfc, UnicodeNormalization}; // For NFC normalization
-
-/// Represents a range of Unicode code points [start, end] inclusive.
-pub type CodepointRange = (u32, u32);
+use unicode_normalization::UnicodeNormalization;
 
-// These would typically come from your CJK_util module/crate
-pub const CJK_IDEOGRAPH_RANGES: &[CodepointRange] = &[
-    (0x4E00, 0x9FFF), // CJK Unified Ideographs
-    (0x3400, 0x4DBF), // CJK Unified Ideographs Extension A
-                      // ... other CJK ideograph ranges
-];
-pub const CJK_PUNCTUATION_RANGE: CodepointRange = (0x3000, 0x303F);
-pub const FULLWIDTH_CHARACTER_RANGES: &[CodepointRange] = &[
-    (0xFF00, 0xFFEF), // Halfwidth and Fullwidth Forms
-                      // ... other fullwidth ranges
-];
+use crate::{
+    cjk_utils::{
+        is_code_point_in_ranges, CodepointRange, CJK_IDEOGRAPH_RANGES, CJK_PUNCTUATION_RANGE,
+        FULLWIDTH_CHARACTER_RANGES,
+    },
+    zh::pinyin::{convert_pinyin, PinyinToneStyle},
+};
 
 pub const BOPOMOFO_RANGE: CodepointRange = (0x3100, 0x312f);
 pub const BOPOMOFO_EXTENDED_RANGE: CodepointRange = (0x31a0, 0x31bf);
@@ -30,132 +18,323 @@ pub const SMALL_FORM_RANGE: CodepointRange = (0xfe50, 0xfe6f);
 pub const VERTICAL_FORM_RANGE: CodepointRange = (0xfe10, 0xfe1f);
 
 /// Chinese character ranges, roughly ordered in order of expected frequency.
-/// In Rust, const array initialization doesn't have a direct spread operator for other const arrays.
-/// We list them explicitly or use a macro if combining many such arrays.
-/// For simplicity, we'll construct it by listing.
-pub const CHINESE_RANGES: &[CodepointRange] = &[
-    // ...CJK_IDEOGRAPH_RANGES (manually list them or use a build script/macro to generate)
-    (0x4E00, 0x9FFF), // CJK Unified Ideographs (example from CJK_IDEOGRAPH_RANGES)
-    (0x3400, 0x4DBF), // CJK Unified Ideographs Extension A (example from CJK_IDEOGRAPH_RANGES)
-    CJK_PUNCTUATION_RANGE,
-    // ...FULLWIDTH_CHARACTER_RANGES (manually list them)
-    (0xFF00, 0xFFEF), // Halfwidth and Fullwidth Forms (example from FULLWIDTH_CHARACTER_RANGES)
-    BOPOMOFO_RANGE,
-    BOPOMOFO_EXTENDED_RANGE,
-    IDEOGRAPHIC_SYMBOLS_AND_PUNCTUATION_RANGE,
-    SMALL_FORM_RANGE,
-    VERTICAL_FORM_RANGE,
-];
-
-/// Checks if a given code point falls within any of the specified ranges.
-///
-/// # Arguments
-/// * `code_point` - The Unicode code point (as u32) to check.
-/// * `ranges` - A slice of `CodepointRange` tuples.
-///
-/// # Returns
-/// `true` if the code point is in any of the ranges, `false` otherwise.
-pub fn is_code_point_in_ranges(code_point: u32, ranges: &[CodepointRange]) -> bool {
-    for &(start, end) in ranges {
-        if code_point >= start && code_point <= end {
-            return true;
-        }
-    }
-    false
-}
+pub static CHINESE_RANGES: LazyLock<Vec<CodepointRange>> = LazyLock::new(|| {
+    CJK_IDEOGRAPH_RANGES
+        .into_iter()
+        .chain([CJK_PUNCTUATION_RANGE])
+        .chain(FULLWIDTH_CHARACTER_RANGES)
+        .chain([
+            BOPOMOFO_RANGE,
+            BOPOMOFO_EXTENDED_RANGE,
+            IDEOGRAPHIC_SYMBOLS_AND_PUNCTUATION_RANGE,
+            SMALL_FORM_RANGE,
+            VERTICAL_FORM_RANGE,
+        ])
+        .collect()
+});
 
 /// Checks if a string contains at least one Chinese character.
-///
-/// # Arguments
-/// * `s` - The string slice to check.
-///
-/// # Returns
-/// `true` if any character in the string is considered Chinese, `false` otherwise.
 pub fn is_string_partially_chinese(s: &str) -> bool {
-    if s.is_empty() {
-        return false;
-    }
-    for c in s.chars() {
-        if is_code_point_chinese(c as u32) {
-            return true;
-        }
-    }
-    false
+    s.chars().any(|c| is_code_point_chinese(c as u32))
 }
 
-/// Checks if a given Unicode code point is considered a Chinese character.
-///
-/// # Arguments
-/// * `code_point` - The Unicode code point (as u32).
-///
-/// # Returns
-/// `true` if the code point is within the defined Chinese character ranges, `false` otherwise.
+/// Checks if a given Unicode code point is within [`CHINESE_RANGES`].
 pub fn is_code_point_chinese(code_point: u32) -> bool {
-    is_code_point_in_ranges(code_point, CHINESE_RANGES)
+    is_code_point_in_ranges(code_point, &CHINESE_RANGES)
 }
 
-// For normalize_pinyin, we need a regex. It's good practice to compile it once.
-// `once_cell::sync::Lazy` is great for this.
-static PINYIN_CLEANUP_REGEX: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r"[\s・:'’-]|\/\/").unwrap() // In JS: /[\s・:'’-]|\/\//g
-});
+static PINYIN_CLEANUP_REGEX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"[\s・:'’-]|\/\/").unwrap());
 
-/// Normalizes a Pinyin string.
-/// This involves NFC normalization, converting to lowercase, and removing specific characters.
+/// Normalizes a Pinyin reading: NFC, lowercased,
+/// with whitespace, separators and `//` removed.
 ///
-/// # Arguments
-/// * `s` - The Pinyin string slice to normalize.
-///
-/// # Returns
-/// A `String` containing the normalized Pinyin.
+/// `Pīn・yīn: 'test'` -> `pīnyīntest`
 pub fn normalize_pinyin(s: &str) -> String {
-    // 1. Normalize to NFC
-    // The .nfc() iterator produces characters, so we collect them into a String.
-    let normalized_s: String = s.nfc().collect();
+    let normalized: String = s.nfc().collect::<String>().to_lowercase();
+    PINYIN_CLEANUP_REGEX
+        .replace_all(&normalized, "")
+        .into_owned()
+}
 
-    // 2. Convert to lowercase
-    let lowercased_s = normalized_s.to_lowercase();
+/// [`normalize_pinyin`], ignoring tones: `nǐ hǎo` & `ni3 hao3` -> `nihao`.
+pub fn normalize_pinyin_toneless(s: &str) -> String {
+    normalize_pinyin_with(s, PinyinToneStyle::Toneless)
+}
 
-    // 3. Replace specified characters with an empty string
-    // The regex crate's replace_all returns a Cow<str> (Clone-on-Write string).
-    // We convert it to an owned String.
+/// [`normalize_pinyin`] with tones rewritten in `style`, see [`convert_pinyin`].
+///
+/// `ni3 hao3` -> `nǐhǎo` (`Marks`)
+pub fn normalize_pinyin_with(s: &str, style: PinyinToneStyle) -> String {
+    let normalized: String = s.nfc().collect::<String>().to_lowercase();
     PINYIN_CLEANUP_REGEX
-        .replace_all(&lowercased_s, "")
+        .replace_all(&convert_pinyin(&normalized, style), "")
         .into_owned()
 }
 
+/// Common characters that differ between traditional & simplified Chinese,
+/// as `traditional, simplified` pairs.
+///
+/// This is not a complete conversion table, only enough to find everyday words
+/// written in the other script. Some traditional characters share a simplified one
+/// (ie: 發 & 髮 -> 发), in which case the first is used for simplified -> traditional,
+/// unless the simplified one is listed in [`SIMPLIFIED_KEPT_IN_TRADITIONAL`].
+#[rustfmt::skip]
+const TRADITIONAL_SIMPLIFIED_PAIRS: &[&str] = &[
+    "愛爱罷罢備备貝贝筆笔畢毕邊边變变賓宾補补財财參参蠶蚕倉仓產产長长",
+    "嘗尝場场廠厂車车徹彻塵尘陳陈稱称懲惩遲迟齒齿衝冲蟲虫醜丑處处傳传",
+    "闖闯創创詞词從从聰聪錯错達达帶带單单擔担當当黨党導导燈灯鄧邓敵敌",
+    "遞递點点電电釣钓調调訂订東东動动鬥斗獨独讀读斷断隊队對对噸吨奪夺",
+    "兒儿爾尔發发髮发罰罚範范飯饭訪访飛飞廢废費费紛纷墳坟奮奋憤愤豐丰",
+    "鳳凤膚肤婦妇復复負负該该蓋盖趕赶幹干剛刚鋼钢綱纲崗岗個个給给鞏巩",
+    "貢贡溝沟構构購购夠够穀谷顧顾關关觀观館馆慣惯廣广規规歸归龜龟軌轨",
+    "櫃柜貴贵國国過过漢汉號号後后護护劃划華华話话畫画壞坏歡欢環环換换",
+    "還还黃黄揮挥輝辉會会匯汇彙汇繪绘貨货獲获機机積积擊击雞鸡極极級级",
+    "幾几際际計计記记紀纪濟济繼继價价駕驾間间監监堅坚艱艰揀拣儉俭撿捡",
+    "簡简見见艦舰劍剑薦荐漸渐將将講讲獎奖膠胶驕骄腳脚餃饺較较階阶節节",
+    "潔洁結结緊紧僅仅盡尽進进經经驚惊靜静鏡镜競竞舊旧舉举劇剧據据懼惧",
+    "覺觉絕绝軍军開开凱凯殼壳課课墾垦懇恳庫库誇夸塊块寬宽礦矿曠旷虧亏",
+    "擴扩闊阔蠟蜡來来賴赖蘭兰攔拦欄栏爛烂勞劳樂乐淚泪類类離离裡里禮礼",
+    "麗丽厲厉勵励歷历曆历聯联連连憐怜簾帘臉脸練练糧粮兩两輛辆諒谅療疗",
+    "遼辽獵猎臨临鄰邻靈灵嶺岭領领劉刘龍龙樓楼爐炉陸陆錄录驢驴論论羅罗",
+    "邏逻蘿萝馬马碼码罵骂嗎吗買买賣卖麥麦滿满貓猫門门們们夢梦彌弥謎谜",
+    "綿绵麵面廟庙滅灭鳴鸣銘铭謀谋畝亩納纳難难腦脑惱恼鬧闹內内擬拟膩腻",
+    "鳥鸟寧宁濃浓農农諾诺歐欧盤盘賠赔噴喷鵬鹏騙骗飄飘頻频評评憑凭撲扑",
+    "齊齐騎骑豈岂啟启氣气棄弃牽牵鉛铅錢钱潛潜淺浅槍枪牆墙搶抢橋桥喬乔",
+    "僑侨竅窍親亲輕轻傾倾慶庆窮穷區区軀躯驅驱權权勸劝確确讓让饒饶擾扰",
+    "繞绕熱热認认榮荣軟软銳锐潤润灑洒賽赛傘伞喪丧掃扫殺杀曬晒傷伤燒烧",
+    "紹绍設设攝摄紳绅審审聲声繩绳勝胜聖圣師师濕湿詩诗識识實实時时勢势",
+    "視视試试飾饰釋释壽寿獸兽書书屬属數数樹树帥帅雙双誰谁稅税順顺說说",
+    "碩硕絲丝飼饲鬆松頌颂訴诉肅肃雖虽隨随歲岁孫孙損损縮缩瑣琐鎖锁臺台",
+    "態态攤摊談谈歎叹湯汤燙烫濤涛討讨騰腾題题體体條条鐵铁聽听廳厅頭头",
+    "圖图塗涂團团襪袜灣湾萬万網网違违圍围為为偉伟衛卫謂谓穩稳問问甕瓮",
+    "臥卧烏乌無无霧雾務务誤误習习係系戲戏細细蝦虾嚇吓鹹咸顯显險险現现",
+    "線线縣县憲宪鄉乡詳详響响項项蕭萧銷销曉晓協协脅胁寫写謝谢興兴選选",
+    "學学尋寻訓训訊讯遜逊壓压鴉鸦啞哑亞亚訝讶煙烟鹽盐嚴严顏颜驗验樣样",
+    "養养陽阳癢痒藥药爺爷業业頁页葉叶醫医儀仪億亿憶忆藝艺議议陰阴銀银",
+    "飲饮隱隐應应營营蠅蝇贏赢擁拥湧涌優优憂忧郵邮猶犹遊游於于魚鱼漁渔",
+    "與与語语獄狱預预禦御譽誉淵渊園园員员圓圆緣缘遠远願愿約约躍跃閱阅",
+    "雲云運运暈晕雜杂災灾載载贊赞讚赞髒脏棗枣竈灶責责擇择則则澤泽賊贼",
+    "贈赠紮扎閘闸齋斋債债氈毡盞盏嶄崭戰战張张漲涨帳帐賬账脹胀趙赵這这",
+    "針针偵侦診诊陣阵鎮镇爭争睜睁徵征證证織织職职執执紙纸誌志製制質质",
+    "鐘钟終终種种腫肿眾众週周軸轴晝昼豬猪諸诸燭烛囑嘱築筑鑄铸專专磚砖",
+    "轉转賺赚莊庄裝装壯壮狀状錐锥準准濁浊資资綜综總总縱纵鄒邹組组鑽钻",
+    "麼么",
+    "礙碍襖袄壩坝擺摆敗败頒颁闆板辦办幫帮綁绑寶宝飽饱報报鮑鲍輩辈繃绷",
+    "斃毙閉闭編编貶贬辯辩辮辫標标錶表別别癟瘪瀕濒濱滨擯摈餅饼撥拨缽钵",
+    "鉑铂駁驳蔔卜佈布層层攙搀讒谗饞馋纏缠鏟铲闡阐顫颤腸肠償偿暢畅鈔钞",
+    "襯衬撐撑誠诚馳驰熾炽籌筹綢绸鋤锄礎础觸触瘡疮純纯綽绰辭辞賜赐蔥葱",
+    "叢丛湊凑竄窜貸贷膽胆撣掸彈弹擋挡檔档島岛禱祷盜盗締缔顛颠墊垫釘钉",
+    "頂顶錠锭凍冻棟栋竇窦瀆渎犢犊賭赌鍍镀緞缎頓顿鈍钝躉趸墮堕額额訛讹",
+    "餓饿鍔锷貳贰閥阀礬矾釩钒販贩紡纺誹诽糞粪楓枫鋒锋瘋疯馮冯縫缝諷讽",
+    "輻辐撫抚輔辅賦赋縛缚訃讣韁缰岡冈鴿鸽閣阁鉻铬頜颌鯁鲠鉤钩詁诂鑼锣",
+    "颳刮掛挂閨闺滾滚鍋锅駭骇韓韩閡阂賀贺轟轰鴻鸿紅红壺壶滬沪嘩哗懷怀",
+    "緩缓喚唤瘓痪煥焕謊谎毀毁賄贿穢秽葷荤渾浑夥伙禍祸譏讥飢饥跡迹績绩",
+    "緝缉輯辑擠挤薊蓟劑剂夾夹莢荚頰颊賈贾鉀钾殲歼箋笺繭茧鹼碱減减檢检",
+    "賤贱鑑鉴濺溅踐践鍵键槳桨蔣蒋醬酱嬌娇澆浇繳缴轎轿傑杰誡诫屆届錦锦",
+    "謹谨燼烬晉晋莖茎鯨鲸徑径頸颈淨净糾纠廄厩駒驹鋸锯捲卷絹绢訣诀鈞钧",
+    "駿骏愾忾鎧铠剴剀頦颏摳抠褲裤膾脍饋馈潰溃綑捆臘腊萊莱攬揽藍蓝籃篮",
+    "覽览濫滥纜缆撈捞嘮唠癆痨鐳镭壘垒稜棱釐厘鯉鲤瀝沥隸隶倆俩鏈链戀恋",
+    "煉炼斂敛殮殓涼凉樑梁瞭了鐐镣鱗鳞凜凛賃赁齡龄鈴铃淩凌欞棂餾馏瀏浏",
+    "聾聋嚨咙籠笼壟垄攏拢隴陇婁娄摟搂簍篓蘆芦顱颅滷卤虜虏魯鲁賂赂祿禄",
+    "鷺鹭閭闾屢屡縷缕慮虑濾滤綠绿巒峦攣挛孿孪灤滦亂乱掄抡輪轮倫伦侖仑",
+    "淪沦綸纶騾骡駱骆絡络瑪玛螞蚂邁迈瞞瞒饅馒蠻蛮謾谩錨锚鉚铆貿贸沒没",
+    "鎂镁悶闷錳锰瞇眯覓觅冪幂緬缅閩闽憫悯黽黾謬谬饃馍鈉钠餒馁擰拧獰狞",
+    "紐纽鈕钮膿脓瘧疟毆殴嘔呕漚沤謳讴龐庞羆罴貧贫蘋苹潑泼頗颇僕仆樸朴",
+    "譜谱淒凄臍脐訖讫遷迁簽签謙谦鉗钳譴谴塹堑嗆呛薔蔷強强蹺跷鍬锹篋箧",
+    "欽钦寢寝氫氢頃顷請请瓊琼趨趋麴曲齲龋顴颧闕阙鵲鹊韌韧紉纫絨绒閏闰",
+    "薩萨鰓鳃騷骚澀涩紗纱篩筛刪删閃闪陝陕贍赡繕缮賞赏賒赊懾慑嬸婶腎肾",
+    "滲渗獅狮屍尸蝕蚀駛驶適适樞枢輸输贖赎術术豎竖閂闩爍烁聳耸慫怂訟讼",
+    "誦诵擻擞蘇苏綏绥筍笋獺獭撻挞擡抬貪贪癱瘫灘滩壇坛譚谭縧绦謄誊銻锑",
+    "屜屉貼贴烴烃銅铜統统禿秃頹颓蛻蜕脫脱鴕鸵馱驮駝驼橢椭窪洼彎弯頑顽",
+    "韋韦爲为濰潍維维葦苇偽伪緯纬餵喂聞闻紋纹撾挝蝸蜗渦涡窩窝嗚呜鎢钨",
+    "誣诬蕪芜吳吴塢坞錫锡犧牺襲袭銑铣轄辖峽峡俠侠狹狭廈厦鍁锨鮮鲜纖纤",
+    "賢贤銜衔閒闲獻献餡馅羨羡廂厢鑲镶囂嚣嘯啸蠍蝎挾挟攜携諧谐瀉泻鋅锌",
+    "釁衅洶汹鏽锈繡绣虛虚噓嘘須须許许敘叙緒绪續续軒轩懸悬癬癣絢绚勳勋",
+    "詢询馴驯鴨鸭閹阉巖岩閻阎豔艳厭厌硯砚彥彦諺谚鴦鸯楊杨揚扬瘍疡瑤瑶",
+    "搖摇堯尧遙遥窯窑謠谣銥铱頤颐遺遗蟻蚁義义詣诣誼谊譯译異异繹绎蔭荫",
+    "櫻樱嬰婴鷹鹰纓缨瑩莹螢萤熒荧穎颖喲哟傭佣癰痈踴踊詠咏鈾铀誘诱輿舆",
+    "娛娱嶼屿籲吁馭驭鴛鸳轅辕鑰钥嶽岳粵粤悅悦鄖郧勻匀隕陨蘊蕴醞酝韻韵",
+    "攢攒暫暂贓赃鑿凿譖谮劄札軋轧鍘铡柵栅詐诈斬斩輾辗棧栈綻绽蟄蛰轍辙",
+    "鍺锗貞贞掙挣猙狰幀帧鄭郑摯挚擲掷幟帜滯滞謅诌皺皱驟骤誅诛矚瞩貯贮",
+    "駐驻樁桩妝妆贅赘墜坠綴缀諄谆茲兹漬渍蹤踪詛诅纘缵儘尽餘余隻只衆众",
+    "乾干鬍胡鬚须蒐搜",
+];
+
+/// Simplified characters that are also written as is in traditional Chinese
+/// (ie: 面 "face" next to 麵 "noodles"), which are only converted from traditional
+/// since turning them into traditional would corrupt ordinary text.
+#[rustfmt::skip]
+const SIMPLIFIED_KEPT_IN_TRADITIONAL: &[&str] = &[
+    "面系后制松谷斗范周志于里丑干几云游御征准扎咸台冲涌划",
+    "了只表布卜梁曲胡搜刮伙卷捆札喂抬厘板余朴仆岩岳凌吁佣",
+];
+
+fn traditional_simplified_pairs() -> impl Iterator<Item = (char, char)> {
+    TRADITIONAL_SIMPLIFIED_PAIRS.iter().flat_map(|pairs| {
+        let chars: Vec<char> = pairs.chars().collect();
+        chars
+            .chunks_exact(2)
+            .map(|pair| (pair[0], pair[1]))
+            .collect::<Vec<_>>()
+    })
+}
+
+static TRADITIONAL_TO_SIMPLIFIED: LazyLock<HashMap<char, char>> =
+    LazyLock::new(|| traditional_simplified_pairs().collect());
+
+static SIMPLIFIED_TO_TRADITIONAL: LazyLock<HashMap<char, char>> = LazyLock::new(|| {
+    let mut map = HashMap::new();
+    for (traditional, simplified) in traditional_simplified_pairs() {
+        if SIMPLIFIED_KEPT_IN_TRADITIONAL
+            .iter()
+            .any(|kept| kept.contains(simplified))
+        {
+            continue;
+        }
+        map.entry(simplified).or_insert(traditional);
+    }
+    map
+});
+
+/// `這個國家` -> `这个国家`, see [`TRADITIONAL_SIMPLIFIED_PAIRS`].
+pub fn convert_traditional_to_simplified(text: &str) -> String {
+    text.chars()
+        .map(|c| *TRADITIONAL_TO_SIMPLIFIED.get(&c).unwrap_or(&c))
+        .collect()
+}
+
+/// `这个国家` -> `這個國家`, see [`TRADITIONAL_SIMPLIFIED_PAIRS`].
+pub fn convert_simplified_to_traditional(text: &str) -> String {
+    text.chars()
+        .map(|c| *SIMPLIFIED_TO_TRADITIONAL.get(&c).unwrap_or(&c))
+        .collect()
+}
+
+#[cfg(test)]
 mod zh_tests {
-    use unicode_normalization::{is_nfc, UnicodeNormalization};
-
-    use crate::zh::chinese::{
-        is_code_point_chinese, is_string_partially_chinese, normalize_pinyin,
-    };
-
-    fn zhtest() {
-        println!(
-            "Is '你好世界' partially Chinese? {}",
-            is_string_partially_chinese("你好世界")
-        ); // true
-        println!(
-            "Is 'Hello' partially Chinese? {}",
-            is_string_partially_chinese("Hello")
-        ); // false
-        println!("Is '世' Chinese? {}", is_code_point_chinese('世' as u32)); // true
-        println!("Is 'A' Chinese? {}", is_code_point_chinese('A' as u32)); // false
-
-        let pinyin1 = "Nǐ hǎo";
-        let pinyin2 = "ni³ hao³ // comment";
-        let pinyin3 = "Pīn・yīn: 'test' - example";
-        println!("'{}' -> '{}'", pinyin1, normalize_pinyin(pinyin1)); // "nǐhǎo"
-        println!("'{}' -> '{}'", pinyin2, normalize_pinyin(pinyin2)); // "ni³hao³"
-        println!("'{}' -> '{}'", pinyin3, normalize_pinyin(pinyin3)); // "pīnyīntestexample"
-
-        // Check if a specific character is NFC (just an example of using the unicode-normalization crate)
-        let precomposed = "é"; // U+00E9
-        let decomposed = "é"; // U+0065 U+0301
-        println!("'{}' is NFC: {}", precomposed, is_nfc(precomposed)); // true
-        println!("'{}' is NFC: {}", decomposed, is_nfc(decomposed)); // false
-        let nfc_decomposed: String = decomposed.nfc().collect();
-        println!("Decomposed '{decomposed}' to NFC: '{nfc_decomposed}'"); // "é"
+    use super::*;
+
+    #[test]
+    fn is_string_partially_chinese_() {
+        assert!(is_string_partially_chinese("你好世界"));
+        assert!(is_string_partially_chinese("abcㄅ"));
+        assert!(is_string_partially_chinese("𠀀"));
+        assert!(!is_string_partially_chinese("Hello"));
+        assert!(!is_string_partially_chinese(""));
+    }
+
+    #[test]
+    fn normalize_pinyin_() {
+        assert_eq!(normalize_pinyin("Nǐ hǎo"), "nǐhǎo");
+        assert_eq!(normalize_pinyin("ni³ hao³ // comment"), "ni³hao³comment");
+        assert_eq!(
+            normalize_pinyin("Pīn・yīn: 'test' - example"),
+            "pīnyīntestexample"
+        );
+        // decomposed `ǐ`
+        assert_eq!(normalize_pinyin("Ni\u{30c}"), "nǐ");
+        assert_eq!(normalize_pinyin("Ni3 hao3"), "ni3hao3");
+        assert_eq!(
+            normalize_pinyin_with("Ni3 hao3", PinyinToneStyle::Marks),
+            normalize_pinyin("nǐ hǎo")
+        );
+        assert_eq!(normalize_pinyin_toneless("ni3 hao3"), "nihao");
+        assert_eq!(normalize_pinyin_toneless("Nǐ hǎo"), "nihao");
+        assert_eq!(
+            normalize_pinyin_with("nǐ hǎo", PinyinToneStyle::Numbers),
+            "ni3hao3"
+        );
+    }
+
+    #[test]
+    fn traditional_simplified() {
+        assert!(traditional_simplified_pairs().all(|(t, s)| t != s));
+        // every traditional character is listed once
+        assert_eq!(
+            TRADITIONAL_TO_SIMPLIFIED.len(),
+            traditional_simplified_pairs().count()
+        );
+        assert!(TRADITIONAL_SIMPLIFIED_PAIRS
+            .iter()
+            .all(|pairs| pairs.chars().count() % 2 == 0));
+        assert_eq!(convert_traditional_to_simplified("這個國家"), "这个国家");
+        assert_eq!(convert_simplified_to_traditional("这个国家"), "這個國家");
+        assert_eq!(convert_traditional_to_simplified("頭髮"), "头发");
+        assert_eq!(convert_simplified_to_traditional("发现"), "發現");
+        assert_eq!(convert_traditional_to_simplified("臺灣銀行"), "台湾银行");
+        assert_eq!(convert_simplified_to_traditional("经济发展"), "經濟發展");
+        assert_eq!(convert_traditional_to_simplified("abc"), "abc");
+        // one-to-many pairs only convert from traditional
+        assert_eq!(convert_simplified_to_traditional("面包"), "面包");
+        assert_eq!(convert_simplified_to_traditional("以后"), "以后");
+        assert_eq!(convert_simplified_to_traditional("星期周末"), "星期周末");
+        assert_eq!(convert_traditional_to_simplified("麵條"), "面条");
+        assert_eq!(convert_traditional_to_simplified("以後"), "以后");
+        assert_eq!(convert_simplified_to_traditional("我吃了"), "我吃了");
+        assert_eq!(convert_simplified_to_traditional("只有"), "只有");
+        assert_eq!(convert_traditional_to_simplified("一隻貓"), "一只猫");
+        assert_eq!(convert_traditional_to_simplified("明瞭"), "明了");
+    }
+
+    #[test]
+    fn language_descriptor() {
+        use crate::{
+            language_d::TextProcessorSetting,
+            languages::{
+                get_all_language_reading_normalizers, get_all_language_text_processors,
+                is_text_lookup_worthy,
+            },
+        };
+
+        assert!(is_text_lookup_worthy("读", "zh"));
+        assert!(!is_text_lookup_worthy("read", "zh"));
+        let normalizer = get_all_language_reading_normalizers()
+            .into_iter()
+            .find(|n| n.iso == "zh")
+            .unwrap();
+        assert_eq!((normalizer.reading_normalizer)("Dú shū"), "dúshū");
+        let processors = get_all_language_text_processors()
+            .into_iter()
+            .find(|p| p.iso == "zh")
+            .unwrap();
+        let variants = processors
+            .pre
+            .iter()
+            .find(|p| p.id == "convert_chinese_variants")
+            .unwrap();
+        let outputs: Vec<String> = variants
+            .processor
+            .options
+            .iter()
+            .map(|option| (variants.processor.process)("讀書", option.clone()))
+            .collect();
+        assert_eq!(outputs, ["讀書", "读书", "讀書"]);
+
+        let tones = processors
+            .pre
+            .iter()
+            .find(|p| p.id == "convert_pinyin_tones")
+            .unwrap();
+        let outputs: Vec<String> = tones
+            .processor
+            .options
+            .iter()
+            .map(|option| (tones.processor.process)("ni3 hǎo", option.clone()))
+            .collect();
+        assert_eq!(outputs, ["ni3 hǎo", "nǐ hǎo", "ni3 hao3"]);
+        let toneless = processors
+            .pre
+            .iter()
+            .find(|p| p.id == "remove_pinyin_tones")
+            .unwrap();
+        assert_eq!(
+            (toneless.processor.process)("nǐ hǎo", TextProcessorSetting::Bool(true)),
+            "ni hao"
+        );
+        assert_eq!(
+            (toneless.processor.process)("读书", TextProcessorSetting::Bool(true)),
+            "读书"
+        );
     }
 }
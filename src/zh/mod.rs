@@ -1 +1,2 @@
 pub mod chinese;
+pub mod pinyin;
@@ -0,0 +1,365 @@
+use std::{collections::HashSet, sync::LazyLock};
+
+use unicode_normalization::UnicodeNormalization;
+
+/// Every toneless pinyin syllable, `ü` written as `ü`.
+#[rustfmt::skip]
+const PINYIN_SYLLABLES: &[&str] = &[
+    "a", "ai", "an", "ang", "ao",
+    "ba", "bai", "ban", "bang", "bao", "bei", "ben", "beng", "bi", "bian", "biao", "bie", "bin", "bing", "bo", "bu",
+    "ca", "cai", "can", "cang", "cao", "ce", "cen", "ceng", "cha", "chai", "chan", "chang", "chao", "che", "chen",
+    "cheng", "chi", "chong", "chou", "chu", "chua", "chuai", "chuan", "chuang", "chui", "chun", "chuo", "ci", "cong",
+    "cou", "cu", "cuan", "cui", "cun", "cuo",
+    "da", "dai", "dan", "dang", "dao", "de", "dei", "den", "deng", "di", "dia", "dian", "diao", "die", "ding", "diu",
+    "dong", "dou", "du", "duan", "dui", "dun", "duo",
+    "e", "ei", "en", "eng", "er",
+    "fa", "fan", "fang", "fei", "fen", "feng", "fo", "fou", "fu",
+    "ga", "gai", "gan", "gang", "gao", "ge", "gei", "gen", "geng", "gong", "gou", "gu", "gua", "guai", "guan", "guang",
+    "gui", "gun", "guo",
+    "ha", "hai", "han", "hang", "hao", "he", "hei", "hen", "heng", "hong", "hou", "hu", "hua", "huai", "huan", "huang",
+    "hui", "hun", "huo",
+    "ji", "jia", "jian", "jiang", "jiao", "jie", "jin", "jing", "jiong", "jiu", "ju", "juan", "jue", "jun",
+    "ka", "kai", "kan", "kang", "kao", "ke", "kei", "ken", "keng", "kong", "kou", "ku", "kua", "kuai", "kuan", "kuang",
+    "kui", "kun", "kuo",
+    "la", "lai", "lan", "lang", "lao", "le", "lei", "leng", "li", "lia", "lian", "liang", "liao", "lie", "lin", "ling",
+    "liu", "lo", "long", "lou", "lu", "luan", "lun", "luo", "lü", "lüe",
+    "ma", "mai", "man", "mang", "mao", "me", "mei", "men", "meng", "mi", "mian", "miao", "mie", "min", "ming", "miu",
+    "mo", "mou", "mu",
+    "na", "nai", "nan", "nang", "nao", "ne", "nei", "nen", "neng", "ni", "nian", "niang", "niao", "nie", "nin", "ning",
+    "niu", "nong", "nou", "nu", "nuan", "nuo", "nü", "nüe",
+    "o", "ou",
+    "pa", "pai", "pan", "pang", "pao", "pei", "pen", "peng", "pi", "pian", "piao", "pie", "pin", "ping", "po", "pou",
+    "pu",
+    "qi", "qia", "qian", "qiang", "qiao", "qie", "qin", "qing", "qiong", "qiu", "qu", "quan", "que", "qun",
+    "ran", "rang", "rao", "re", "ren", "reng", "ri", "rong", "rou", "ru", "rua", "ruan", "rui", "run", "ruo",
+    "sa", "sai", "san", "sang", "sao", "se", "sen", "seng", "sha", "shai", "shan", "shang", "shao", "she", "shei",
+    "shen", "sheng", "shi", "shou", "shu", "shua", "shuai", "shuan", "shuang", "shui", "shun", "shuo", "si", "song",
+    "sou", "su", "suan", "sui", "sun", "suo",
+    "ta", "tai", "tan", "tang", "tao", "te", "tei", "teng", "ti", "tian", "tiao", "tie", "ting", "tong", "tou", "tu",
+    "tuan", "tui", "tun", "tuo",
+    "wa", "wai", "wan", "wang", "wei", "wen", "weng", "wo", "wu",
+    "xi", "xia", "xian", "xiang", "xiao", "xie", "xin", "xing", "xiong", "xiu", "xu", "xuan", "xue", "xun",
+    "ya", "yan", "yang", "yao", "ye", "yi", "yin", "ying", "yo", "yong", "you", "yu", "yuan", "yue", "yun",
+    "za", "zai", "zan", "zang", "zao", "ze", "zei", "zen", "zeng", "zha", "zhai", "zhan", "zhang", "zhao", "zhe",
+    "zhei", "zhen", "zheng", "zhi", "zhong", "zhou", "zhu", "zhua", "zhuai", "zhuan", "zhuang", "zhui", "zhun", "zhuo",
+    "zi", "zong", "zou", "zu", "zuan", "zui", "zun", "zuo",
+];
+
+static PINYIN_SYLLABLE_SET: LazyLock<HashSet<&'static str>> =
+    LazyLock::new(|| PINYIN_SYLLABLES.iter().copied().collect());
+
+/// Longest syllable in [`PINYIN_SYLLABLES`] (`chuang`, `shuang`, ...), in characters.
+const MAX_SYLLABLE_LEN: usize = 6;
+
+/// Combining tone marks, indexed by tone number - 1.
+const TONE_MARKS: [char; 4] = ['\u{304}', '\u{301}', '\u{30c}', '\u{300}'];
+
+/// How [`convert_pinyin`] writes tones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PinyinToneStyle {
+    /// `nǐ hǎo`
+    Marks,
+    /// `ni3 hao3`, the neutral tone is `5`.
+    Numbers,
+    /// `ni hao`
+    Toneless,
+}
+
+/// A pinyin syllable split into its toneless letters and its tone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Syllable {
+    /// The syllable as written, including its tone number.
+    raw: String,
+    /// Lowercase and NFC, `v` & `u:` written as `ü`.
+    letters: String,
+    /// `1..=4`, `5` for the neutral tone, `None` if the syllable had no tone.
+    tone: Option<u8>,
+}
+
+/// A single letter of a pinyin word.
+struct Letter<'a> {
+    /// The letter as written, `u:` is a single letter.
+    raw: &'a str,
+    letter: char,
+    tone: Option<u8>,
+}
+
+/// Toneless, lowercase form of `c` (`ǎ` -> `a`, `ǚ` -> `ü`, `V` -> `ü`), with its tone mark.
+fn strip_tone_mark(c: char) -> (char, Option<u8>) {
+    let mut tone = None;
+    let letter: String = c
+        .nfd()
+        .filter(|m| match TONE_MARKS.iter().position(|mark| mark == m) {
+            Some(i) => {
+                tone = Some(i as u8 + 1);
+                false
+            }
+            None => true,
+        })
+        .nfc()
+        .flat_map(char::to_lowercase)
+        .collect();
+    let letter = match letter.as_str() {
+        "v" => 'ü',
+        _ => letter.chars().next().unwrap_or(c),
+    };
+    (letter, tone)
+}
+
+fn is_pinyin_letter(c: char) -> bool {
+    let (letter, _) = strip_tone_mark(c);
+    letter.is_ascii_lowercase() || letter == 'ü'
+}
+
+/// Splits a run of pinyin letters & tone numbers (no separators) into syllables,
+/// preferring the longest syllable at each position.
+///
+/// `None` if `word` can't be fully split into valid syllables.
+fn segment_word(word: &str) -> Option<Vec<Syllable>> {
+    let mut letters: Vec<Letter> = Vec::new();
+    for (i, c) in word.char_indices() {
+        match c {
+            // a tone number must follow a letter
+            '0'..='5' if letters.last().is_some_and(|l| !l.letter.is_ascii_digit()) => {
+                letters.push(Letter {
+                    raw: &word[i..i + 1],
+                    letter: c,
+                    tone: None,
+                });
+            }
+            ':' => {
+                let last = letters
+                    .last_mut()
+                    .filter(|l| l.raw == "u" || l.raw == "U")?;
+                last.raw = &word[i - 1..i + 1];
+                last.letter = 'ü';
+            }
+            _ => {
+                let (letter, tone) = strip_tone_mark(c);
+                letters.push(Letter {
+                    raw: &word[i..i + c.len_utf8()],
+                    letter,
+                    tone,
+                });
+            }
+        }
+    }
+    let mut failed = vec![false; letters.len() + 1];
+    segment_from(&letters, 0, &mut failed)
+}
+
+fn segment_from(letters: &[Letter], start: usize, failed: &mut [bool]) -> Option<Vec<Syllable>> {
+    if start == letters.len() {
+        return Some(Vec::new());
+    }
+    if failed[start] {
+        return None;
+    }
+    let max_len = MAX_SYLLABLE_LEN.min(letters.len() - start);
+    for len in (1..=max_len).rev() {
+        let mut end = start + len;
+        let syllable_letters: String = letters[start..end].iter().map(|l| l.letter).collect();
+        if !PINYIN_SYLLABLE_SET.contains(syllable_letters.as_str()) {
+            continue;
+        }
+        let mut tone = letters[start..end].iter().find_map(|l| l.tone);
+        if let Some(number) = letters.get(end).filter(|l| matches!(l.letter, '0'..='5')) {
+            // `0` is sometimes used for the neutral tone too
+            tone = Some(match number.letter {
+                '0' => 5,
+                d => d as u8 - b'0',
+            });
+            end += 1;
+        }
+        if let Some(mut rest) = segment_from(letters, end, failed) {
+            rest.insert(
+                0,
+                Syllable {
+                    raw: letters[start..end].iter().map(|l| l.raw).collect(),
+                    letters: syllable_letters,
+                    tone,
+                },
+            );
+            return Some(rest);
+        }
+    }
+    failed[start] = true;
+    None
+}
+
+/// Puts `tone`'s mark on the vowel pinyin spelling rules choose:
+/// `a` or `e` if present, the `o` of `ou`, otherwise the last vowel.
+fn mark_syllable(letters: &str, tone: u8) -> String {
+    let Some(&mark) = TONE_MARKS.get(tone as usize - 1) else {
+        return letters.to_string();
+    };
+    let chars: Vec<char> = letters.chars().collect();
+    let index = chars
+        .iter()
+        .position(|c| matches!(c, 'a' | 'e'))
+        .or_else(|| {
+            letters
+                .find("ou")
+                .map(|_| chars.iter().position(|c| *c == 'o').unwrap())
+        })
+        .or_else(|| chars.iter().rposition(|c| "aeiouü".contains(*c)));
+    let mut marked = String::with_capacity(letters.len() + 2);
+    for (i, c) in chars.iter().enumerate() {
+        marked.push(*c);
+        if Some(i) == index {
+            marked.push(mark);
+        }
+    }
+    marked.nfc().collect()
+}
+
+fn write_syllable(syllable: &Syllable, style: PinyinToneStyle, output: &mut String) {
+    match (style, syllable.tone) {
+        (PinyinToneStyle::Marks, Some(tone)) if tone <= 4 => {
+            output.push_str(&mark_syllable(&syllable.letters, tone));
+        }
+        (PinyinToneStyle::Numbers, tone) => {
+            output.push_str(&syllable.letters);
+            output.push(char::from(b'0' + tone.unwrap_or(5)));
+        }
+        _ => output.push_str(&syllable.letters),
+    }
+}
+
+/// Splits `text` into pinyin syllables, ie: `nǐhǎo` -> `["nǐ", "hǎo"]`.
+///
+/// Whitespace, apostrophes and other separators end a syllable and are dropped,
+/// so `xi'an` can be told apart from `xian`.
+/// Tone marks & numbers are kept on their syllable (`ni3hao3` -> `["ni3", "hao3"]`).
+///
+/// Returns `None` if any part of `text` isn't valid pinyin.
+pub fn segment_pinyin(text: &str) -> Option<Vec<String>> {
+    let mut syllables = Vec::new();
+    for word in pinyin_words(text) {
+        if let PinyinWord::Word(word) = word {
+            syllables.extend(segment_word(word)?.into_iter().map(|syllable| syllable.raw));
+        }
+    }
+    Some(syllables)
+}
+
+enum PinyinWord<'a> {
+    /// A run of pinyin letters, tone marks & tone numbers.
+    Word(&'a str),
+    /// Anything between words, ie: spaces & apostrophes.
+    Separator(&'a str),
+}
+
+fn pinyin_words(text: &str) -> Vec<PinyinWord<'_>> {
+    let mut words = Vec::new();
+    let mut start = 0;
+    let mut in_word = None;
+    for (i, c) in text.char_indices() {
+        let is_word = is_pinyin_letter(c) || c.is_ascii_digit() || c == ':';
+        if in_word != Some(is_word) {
+            if let Some(was_word) = in_word {
+                words.push(match was_word {
+                    true => PinyinWord::Word(&text[start..i]),
+                    false => PinyinWord::Separator(&text[start..i]),
+                });
+            }
+            start = i;
+            in_word = Some(is_word);
+        }
+    }
+    match in_word {
+        Some(true) => words.push(PinyinWord::Word(&text[start..])),
+        Some(false) => words.push(PinyinWord::Separator(&text[start..])),
+        None => {}
+    }
+    words
+}
+
+/// Rewrites the tones of every pinyin word in `text` in `style`,
+/// lowercasing it and keeping separators as they are.
+///
+/// `ni3 hao3` -> `nǐ hǎo` (`Marks`), `nǐhǎo` -> `ni3hao3` (`Numbers`).
+/// Words that can't be segmented into syllables are only lowercased.
+pub fn convert_pinyin(text: &str, style: PinyinToneStyle) -> String {
+    let text: String = text.nfc().collect();
+    let mut output = String::with_capacity(text.len());
+    for word in pinyin_words(&text) {
+        match word {
+            PinyinWord::Separator(separator) => output.push_str(separator),
+            PinyinWord::Word(word) => match segment_word(word) {
+                Some(syllables) => {
+                    for syllable in &syllables {
+                        write_syllable(syllable, style, &mut output);
+                    }
+                }
+                None => output.push_str(&word.to_lowercase()),
+            },
+        }
+    }
+    output
+}
+
+/// `ni3 hao3` -> `nǐ hǎo`
+pub fn pinyin_numbers_to_marks(text: &str) -> String {
+    convert_pinyin(text, PinyinToneStyle::Marks)
+}
+
+/// `nǐ hǎo` -> `ni3 hao3`
+pub fn pinyin_marks_to_numbers(text: &str) -> String {
+    convert_pinyin(text, PinyinToneStyle::Numbers)
+}
+
+/// `nǐ hǎo` / `ni3 hao3` -> `ni hao`
+pub fn pinyin_to_toneless(text: &str) -> String {
+    convert_pinyin(text, PinyinToneStyle::Toneless)
+}
+
+#[cfg(test)]
+mod pinyin_tests {
+    use super::*;
+
+    #[test]
+    fn segment() {
+        assert_eq!(segment_pinyin("nǐhǎo").unwrap(), ["nǐ", "hǎo"]);
+        assert_eq!(segment_pinyin("ni3hao3").unwrap(), ["ni3", "hao3"]);
+        assert_eq!(
+            segment_pinyin("Zhongguo ren").unwrap(),
+            ["Zhong", "guo", "ren"]
+        );
+        assert_eq!(segment_pinyin("xi'an").unwrap(), ["xi", "an"]);
+        assert_eq!(segment_pinyin("xian").unwrap(), ["xian"]);
+        assert_eq!(segment_pinyin("lu:4se4").unwrap(), ["lu:4", "se4"]);
+        assert_eq!(segment_pinyin("nv3ren2").unwrap(), ["nv3", "ren2"]);
+        assert_eq!(segment_pinyin("hello"), None);
+        assert_eq!(segment_pinyin("ni7"), None);
+    }
+
+    #[test]
+    fn numbers_to_marks() {
+        assert_eq!(pinyin_numbers_to_marks("ni3 hao3"), "nǐ hǎo");
+        assert_eq!(pinyin_numbers_to_marks("ni3hao3"), "nǐhǎo");
+        assert_eq!(pinyin_numbers_to_marks("Zhong1guo2"), "zhōngguó");
+        assert_eq!(pinyin_numbers_to_marks("dou1 shou3 gui3"), "dōu shǒu guǐ");
+        assert_eq!(pinyin_numbers_to_marks("lu:4 nu:3"), "lǜ nǚ");
+        assert_eq!(pinyin_numbers_to_marks("ma5 ma0 ma"), "ma ma ma");
+        assert_eq!(pinyin_numbers_to_marks("liu2"), "liú");
+        assert_eq!(pinyin_numbers_to_marks("hello"), "hello");
+    }
+
+    #[test]
+    fn marks_to_numbers() {
+        assert_eq!(pinyin_marks_to_numbers("nǐ hǎo"), "ni3 hao3");
+        assert_eq!(pinyin_marks_to_numbers("Zhōngguó"), "zhong1guo2");
+        assert_eq!(pinyin_marks_to_numbers("lǜ"), "lü4");
+        assert_eq!(pinyin_marks_to_numbers("ma"), "ma5");
+        // decomposed tone mark
+        assert_eq!(pinyin_marks_to_numbers("ni\u{30c}"), "ni3");
+    }
+
+    #[test]
+    fn toneless() {
+        assert_eq!(pinyin_to_toneless("nǐ hǎo"), "ni hao");
+        assert_eq!(pinyin_to_toneless("ni3hao3"), "nihao");
+        assert_eq!(pinyin_to_toneless("lǜ"), "lü");
+    }
+}